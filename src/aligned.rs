@@ -0,0 +1,549 @@
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::{Deref, DerefMut, Range, RangeBounds},
+    ptr::{self, NonNull},
+    slice,
+};
+
+use crate::{create, util::normalize_range, OffsetVec, SliceLike};
+
+/// Byte buffer, the start address always aligned to `ALIGN`
+///
+/// Keep alignment across reallocations, e.g
+/// [`reserve`], [`shrink_to_fit`] and [`split_off`]
+///
+/// # Examples
+///
+/// ```
+/// use offset_vec::{AlignedVec, Offset};
+///
+/// let mut buf = AlignedVec::<64>::new();
+/// buf.extend_from_slice(&[0; 100]);
+/// assert_eq!(buf.as_ptr() as usize % 64, 0);
+///
+/// let window = buf.offset_aligned_mut(64);
+/// assert!(window.is_aligned_to(64));
+/// assert_eq!(window.len(), 36);
+/// ```
+///
+/// [`reserve`]: AlignedVec::reserve
+/// [`shrink_to_fit`]: AlignedVec::shrink_to_fit
+/// [`split_off`]: AlignedVec::split_off
+pub struct AlignedVec<const ALIGN: usize> {
+    ptr: NonNull<u8>,
+    len: usize,
+    cap: usize,
+}
+
+// SAFETY: AlignedVec owns bytes like `Vec<u8>`
+unsafe impl<const ALIGN: usize> Send for AlignedVec<ALIGN> {}
+// SAFETY: AlignedVec owns bytes like `Vec<u8>`
+unsafe impl<const ALIGN: usize> Sync for AlignedVec<ALIGN> {}
+
+impl<const ALIGN: usize> AlignedVec<ALIGN> {
+    const VALID_ALIGN: () = assert!(ALIGN.is_power_of_two(), "ALIGN must be a power of two");
+
+    /// Alignment of the start address
+    pub const ALIGN: usize = ALIGN;
+
+    #[inline]
+    pub const fn new() -> Self {
+        let () = Self::VALID_ALIGN;
+        let dangling = ptr::without_provenance_mut(ALIGN);
+        // SAFETY: ALIGN is non-zero
+        let ptr = unsafe { NonNull::new_unchecked(dangling) };
+        Self { ptr, len: 0, cap: 0 }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut this = Self::new();
+        this.set_capacity(capacity);
+        this
+    }
+
+    #[track_caller]
+    fn layout(capacity: usize) -> Layout {
+        Layout::from_size_align(capacity, ALIGN).expect("capacity overflow")
+    }
+
+    #[track_caller]
+    fn set_capacity(&mut self, capacity: usize) {
+        debug_assert!(capacity >= self.len);
+
+        if capacity == self.cap {
+            return;
+        }
+
+        let new_layout = Self::layout(capacity);
+        let ptr = if self.cap == 0 {
+            // SAFETY: capacity is non-zero
+            unsafe { alloc(new_layout) }
+        } else if capacity == 0 {
+            // SAFETY: allocated by same layout
+            unsafe { dealloc(self.ptr.as_ptr(), Self::layout(self.cap)) };
+            self.ptr = Self::new().ptr;
+            self.cap = 0;
+            return;
+        } else {
+            // SAFETY: allocated by same layout, and new size is non-zero
+            unsafe { realloc(self.ptr.as_ptr(), Self::layout(self.cap), capacity) }
+        };
+
+        let Some(ptr) = NonNull::new(ptr) else {
+            handle_alloc_error(new_layout)
+        };
+        self.ptr = ptr;
+        self.cap = capacity;
+    }
+
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: ptr is aligned and non-null, 0..len is initialized
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: ptr is aligned and non-null, 0..len is initialized
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required > self.cap {
+            let new_cap = required.max(self.cap.saturating_mul(2)).max(8);
+            self.set_capacity(new_cap);
+        }
+    }
+
+    #[track_caller]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required > self.cap {
+            self.set_capacity(required);
+        }
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.set_capacity(self.len);
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if min_capacity < self.cap {
+            self.set_capacity(min_capacity.max(self.len));
+        }
+    }
+
+    #[track_caller]
+    pub fn push(&mut self, value: u8) {
+        if self.len == self.cap {
+            self.reserve(1);
+        }
+        // SAFETY: len < cap
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<u8> {
+        let last = *self.as_slice().last()?;
+        self.len -= 1;
+        Some(last)
+    }
+
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, other: &[u8]) {
+        self.reserve(other.len());
+        // SAFETY: reserved, and other cannot overlap with spare capacity
+        unsafe {
+            let dst = self.ptr.as_ptr().add(self.len);
+            ptr::copy_nonoverlapping(other.as_ptr(), dst, other.len());
+        }
+        self.len += other.len();
+    }
+
+    #[track_caller]
+    pub fn resize(&mut self, new_len: usize, value: u8) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        let additional = new_len - self.len;
+        self.reserve(additional);
+        // SAFETY: reserved
+        unsafe { self.ptr.as_ptr().add(self.len).write_bytes(value, additional) };
+        self.len = new_len;
+    }
+
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: u8) {
+        let len = self.len;
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        self.push(element);
+        self.as_mut_slice()[index..].rotate_right(1);
+    }
+
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> u8 {
+        let len = self.len;
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+        let elem = self[index];
+        self.copy_within(index+1.., index);
+        self.len -= 1;
+        elem
+    }
+
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> u8 {
+        let len = self.len;
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+        let elem = self[index];
+        self[index] = self[len-1];
+        self.len -= 1;
+        elem
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Splits the collection into two at the given index,
+    /// the other half also aligned to `ALIGN`
+    #[track_caller]
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len;
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        let other = Self::from(&self[at..]);
+        self.len = at;
+        other
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        self.extend_from_slice(other);
+        other.clear();
+    }
+
+    #[track_caller]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> AlignedDrain<'_, ALIGN> {
+        let range = normalize_range(range, self.len);
+        AlignedDrain { iter: range.clone(), range, vec: self }
+    }
+
+    pub fn retain_mut<F: FnMut(&mut u8) -> bool>(&mut self, mut f: F) {
+        let mut write = 0;
+        for read in 0..self.len {
+            if f(&mut self[read]) {
+                self[write] = self[read];
+                write += 1;
+            }
+        }
+        self.len = write;
+    }
+
+    pub fn retain<F: FnMut(&u8) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Create [`OffsetVec`], `offset` must be multiple of `ALIGN`
+    ///
+    /// # Panics
+    /// - `offset` is not multiple of `ALIGN`
+    /// - `offset` out of length
+    #[track_caller]
+    pub fn offset_aligned(self, offset: usize) -> OffsetVec<Self> {
+        check_offset_aligned(offset, ALIGN);
+        create(self, offset)
+    }
+
+    /// Create [`OffsetVec`], `offset` must be multiple of `ALIGN`
+    ///
+    /// # Panics
+    /// - `offset` is not multiple of `ALIGN`
+    /// - `offset` out of length
+    #[track_caller]
+    pub fn offset_aligned_mut(&mut self, offset: usize) -> OffsetVec<&mut Self> {
+        check_offset_aligned(offset, ALIGN);
+        create(self, offset)
+    }
+}
+
+#[track_caller]
+fn check_offset_aligned(offset: usize, align: usize) {
+    if !offset.is_multiple_of(align) {
+        #[cold]
+        #[track_caller]
+        #[inline(never)]
+        fn fail(offset: usize, align: usize) -> ! {
+            panic!("offset {offset} is not aligned to {align}");
+        }
+        fail(offset, align)
+    }
+}
+
+impl<const ALIGN: usize> Drop for AlignedVec<ALIGN> {
+    fn drop(&mut self) {
+        if self.cap != 0 {
+            // SAFETY: allocated by same layout
+            unsafe { dealloc(self.ptr.as_ptr(), Self::layout(self.cap)) };
+        }
+    }
+}
+
+impl<const ALIGN: usize> Default for AlignedVec<ALIGN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const ALIGN: usize> Clone for AlignedVec<ALIGN> {
+    fn clone(&self) -> Self {
+        Self::from(self.as_slice())
+    }
+}
+
+impl<const ALIGN: usize> fmt::Debug for AlignedVec<ALIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<const ALIGN: usize> Deref for AlignedVec<ALIGN> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+impl<const ALIGN: usize> DerefMut for AlignedVec<ALIGN> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<const ALIGN: usize> AsRef<[u8]> for AlignedVec<ALIGN> {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+impl<const ALIGN: usize> AsMut<[u8]> for AlignedVec<ALIGN> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl<const ALIGN: usize> PartialEq for AlignedVec<ALIGN> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl<const ALIGN: usize> Eq for AlignedVec<ALIGN> {}
+impl<const ALIGN: usize> PartialEq<[u8]> for AlignedVec<ALIGN> {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_slice() == other
+    }
+}
+impl<const ALIGN: usize, const N: usize> PartialEq<[u8; N]> for AlignedVec<ALIGN> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<const ALIGN: usize> Hash for AlignedVec<ALIGN> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<const ALIGN: usize> From<&[u8]> for AlignedVec<ALIGN> {
+    fn from(value: &[u8]) -> Self {
+        let mut this = Self::with_capacity(value.len());
+        this.extend_from_slice(value);
+        this
+    }
+}
+
+impl<const ALIGN: usize> Extend<u8> for AlignedVec<ALIGN> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|elem| self.push(elem));
+    }
+}
+impl<'a, const ALIGN: usize> Extend<&'a u8> for AlignedVec<ALIGN> {
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<const ALIGN: usize> FromIterator<u8> for AlignedVec<ALIGN> {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+/// Draining iterator of [`AlignedVec`]
+pub struct AlignedDrain<'a, const ALIGN: usize> {
+    vec: &'a mut AlignedVec<ALIGN>,
+    range: Range<usize>,
+    iter: Range<usize>,
+}
+
+impl<const ALIGN: usize> fmt::Debug for AlignedDrain<'_, ALIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AlignedDrain")
+            .field(&&self.vec[self.iter.clone()])
+            .finish()
+    }
+}
+
+impl<const ALIGN: usize> Iterator for AlignedDrain<'_, ALIGN> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|i| self.vec[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<const ALIGN: usize> DoubleEndedIterator for AlignedDrain<'_, ALIGN> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|i| self.vec[i])
+    }
+}
+impl<const ALIGN: usize> ExactSizeIterator for AlignedDrain<'_, ALIGN> {}
+impl<const ALIGN: usize> FusedIterator for AlignedDrain<'_, ALIGN> {}
+
+impl<const ALIGN: usize> Drop for AlignedDrain<'_, ALIGN> {
+    fn drop(&mut self) {
+        let Range { start, end } = self.range;
+        let len = self.vec.len;
+        self.vec.copy_within(end..len, start);
+        self.vec.len = len - (end - start);
+    }
+}
+
+impl<T, V: SliceLike<Slice = [T]>> OffsetVec<V> {
+    /// Check the start address of offset slice is aligned to `align`
+    ///
+    /// # Panics
+    /// - `align` is not a power of two
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use offset_vec::{AlignedVec, Offset};
+    ///
+    /// let mut buf = AlignedVec::<16>::from(&[0; 64][..]);
+    ///
+    /// assert!(buf.offset_mut(32).is_aligned_to(16));
+    /// assert!(!buf.offset_mut(33).is_aligned_to(16));
+    /// ```
+    #[track_caller]
+    pub fn is_aligned_to(&self, align: usize) -> bool {
+        assert!(align.is_power_of_two(), "align must be a power of two");
+        self.as_slice().as_ptr().addr() & (align-1) == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_alignment() {
+        let mut buf = AlignedVec::<128>::new();
+        for i in 0..1000 {
+            buf.push(i as u8);
+            assert_eq!(buf.as_ptr().addr() % 128, 0);
+        }
+        buf.truncate(3);
+        buf.shrink_to_fit();
+        assert_eq!(buf.capacity(), 3);
+        assert_eq!(buf.as_ptr().addr() % 128, 0);
+        assert_eq!(buf, [0, 1, 2]);
+
+        let other = buf.split_off(1);
+        assert_eq!(other.as_ptr().addr() % 128, 0);
+        assert_eq!(other, [1, 2]);
+        assert_eq!(buf, [0]);
+
+        buf.shrink_to_fit();
+        buf.clear();
+        buf.shrink_to_fit();
+        assert_eq!(buf.capacity(), 0);
+        assert_eq!(buf.as_ptr().addr() % 128, 0);
+    }
+
+    #[test]
+    fn drain() {
+        let mut buf = AlignedVec::<8>::from(&[0, 1, 2, 3, 4, 5][..]);
+        assert_eq!(buf.drain(1..3).rev().collect::<alloc::vec::Vec<_>>(), [2, 1]);
+        assert_eq!(buf, [0, 3, 4, 5]);
+        buf.drain(2..);
+        assert_eq!(buf, [0, 3]);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut buf = AlignedVec::<8>::from(&[0, 1, 2][..]);
+        buf.insert(1, 4);
+        assert_eq!(buf, [0, 4, 1, 2]);
+        assert_eq!(buf.remove(0), 0);
+        assert_eq!(buf.swap_remove(0), 4);
+        assert_eq!(buf, [2, 1]);
+        buf.retain(|&x| x != 2);
+        assert_eq!(buf, [1]);
+    }
+
+    #[test]
+    #[should_panic = "offset 3 is not aligned to 4"]
+    fn unaligned_offset() {
+        let buf = AlignedVec::<4>::from(&[0; 8][..]);
+        let _ = buf.offset_aligned(3);
+    }
+}
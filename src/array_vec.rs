@@ -0,0 +1,345 @@
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut, RangeBounds},
+};
+
+use crate::{uninit::{self, Prefix, UninitDrain}, CapacityError};
+
+/// Fixed-capacity vector stored inline, never allocate
+///
+/// Growing beyond `N` elements fails through
+/// [`try_push`](ArrayVecLike::try_push) and [`try_insert`](ArrayVecLike::try_insert),
+/// and panics through infallible methods like [`push`](ArrayVecLike::push)
+///
+/// # Examples
+///
+/// ```
+/// use offset_vec::{ArrayVecLike, Offset};
+///
+/// let mut vec = ArrayVecLike::<u8, 4>::new();
+/// let mut vec1 = vec.offset_mut(0);
+///
+/// assert_eq!(vec1.try_push(1), Ok(()));
+/// assert_eq!(vec1.try_push(2), Ok(()));
+///
+/// let mut vec2 = vec.offset_mut(1);
+/// assert_eq!(vec2.try_push(3), Ok(()));
+/// assert_eq!(vec2.try_push(4), Ok(()));
+/// assert_eq!(vec2.try_push(5).unwrap_err().into_element(), 5);
+///
+/// assert_eq!(vec, [1, 2, 3, 4]);
+/// ```
+pub struct ArrayVecLike<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVecLike<T, N> {
+    /// Capacity of the vector
+    pub const CAPACITY: usize = N;
+
+    #[inline]
+    pub const fn new() -> Self {
+        Self { buf: [const { MaybeUninit::uninit() }; N], len: 0 }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `self.len() == 0`
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `self.len() == N`
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Always `N`
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// `N - self.len()`
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: `buf[..len]` is initialized
+        unsafe { uninit::as_slice(&self.buf, self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: `buf[..len]` is initialized
+        unsafe { uninit::as_mut_slice(&mut self.buf, self.len) }
+    }
+
+    #[inline]
+    fn prefix(&mut self) -> Prefix<'_, T> {
+        // SAFETY: `buf[..len]` is initialized
+        unsafe { Prefix::new(&mut self.buf, &mut self.len) }
+    }
+
+    /// Push a value, return the error when the vector is full
+    pub fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.prefix().try_push(value)
+    }
+
+    /// Push a value
+    ///
+    /// # Panics
+    /// - the vector is full
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            capacity_overflow(N)
+        }
+    }
+
+    /// Insert a value, return the error when the vector is full
+    ///
+    /// # Panics
+    /// - `index > len`
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.prefix().try_insert(index, element)
+    }
+
+    /// Insert a value
+    ///
+    /// # Panics
+    /// - `index > len`
+    /// - the vector is full
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: T) {
+        if self.try_insert(index, element).is_err() {
+            capacity_overflow(N)
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.prefix().pop()
+    }
+
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        self.prefix().remove(index)
+    }
+
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.prefix().swap_remove(index)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.prefix().truncate(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        self.prefix().retain_mut(f);
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    #[track_caller]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> UninitDrain<'_, T> {
+        self.prefix().drain(range)
+    }
+
+    /// Split into two at `at`, the tail is moved into returned vector
+    ///
+    /// # Panics
+    /// - `at > len`
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len;
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        self.drain(at..).collect()
+    }
+
+    /// Move all elements of `other` into `self`
+    ///
+    /// # Panics
+    /// - total length exceeds `N`
+    #[track_caller]
+    pub fn append(&mut self, other: &mut Self) {
+        if other.len > self.remaining_capacity() {
+            capacity_overflow(N)
+        }
+        self.extend(other.drain(..));
+    }
+}
+
+#[cold]
+#[track_caller]
+#[inline(never)]
+pub(crate) fn capacity_overflow(cap: usize) -> ! {
+    panic!("capacity overflow, fixed capacity is {cap}")
+}
+
+impl<T, const N: usize> Drop for ArrayVecLike<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVecLike<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVecLike<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVecLike<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVecLike<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+impl<T, const N: usize> DerefMut for ArrayVecLike<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayVecLike<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+impl<T, const N: usize> AsMut<[T]> for ArrayVecLike<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVecLike<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl<T: Eq, const N: usize> Eq for ArrayVecLike<T, N> {}
+impl<T: PartialEq, const N: usize> PartialEq<[T]> for ArrayVecLike<T, N> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<[T; M]> for ArrayVecLike<T, N> {
+    fn eq(&self, other: &[T; M]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for ArrayVecLike<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayVecLike<T, N> {
+    /// # Panics
+    /// - total length exceeds `N`
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|elem| self.push(elem));
+    }
+}
+
+impl<'a, T: Copy + 'a, const N: usize> Extend<&'a T> for ArrayVecLike<T, N> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayVecLike<T, N> {
+    /// # Panics
+    /// - iterator yields more than `N` elements
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{rc::Rc, vec::Vec};
+
+    #[test]
+    fn drop_elements() {
+        let rc = Rc::new(());
+        let mut vec = ArrayVecLike::<_, 4>::new();
+        vec.extend([rc.clone(), rc.clone(), rc.clone()]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        let mut drain = vec.drain(0..2);
+        drop(drain.next());
+        drop(drain);
+        assert_eq!(vec.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 2);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut vec = ArrayVecLike::<i32, 4>::from_iter([1, 2, 3]);
+        vec.insert(1, 4);
+        assert_eq!(vec, [1, 4, 2, 3]);
+        assert_eq!(vec.try_insert(0, 5), Err(CapacityError::new(5)));
+        assert_eq!(vec.remove(0), 1);
+        assert_eq!(vec.swap_remove(0), 4);
+        assert_eq!(vec, [3, 2]);
+
+        vec.retain(|&n| n != 3);
+        assert_eq!(vec, [2]);
+
+        let tail = vec.split_off(0);
+        assert!(vec.is_empty());
+        assert_eq!(tail.iter().copied().collect::<Vec<_>>(), [2]);
+    }
+
+    #[test]
+    #[should_panic = "capacity overflow"]
+    fn push_overflow() {
+        let mut vec = ArrayVecLike::<i32, 1>::new();
+        vec.push(1);
+        vec.push(2);
+    }
+}
@@ -0,0 +1,278 @@
+/// Implement the vector traits for a newtype by forwarding to one field
+///
+/// Default implements [`SeqLike`], [`SliceLike`], [`SliceLikeMut`], [`Truncatable`], [`Growable`],
+/// [`Drainable`], [`SeqVecLike`], [`VecLikeSolid`], [`Offset`] and [`OffsetCheckRef`],
+/// which needs a contiguous field like [`Vec`](alloc::vec::Vec).
+/// Otherwise list the traits after `;`, e.g `; [SeqLike, Truncatable, Growable]`
+///
+/// Generic parameters are written in `impl[...]` before the type.
+/// Prefer the field type as a parameter, with a concrete field like `Vec<T>`
+/// the higher-ranked `ElemRef<'_>` bound of [`retain`] forces `Self: 'static`
+///
+/// # Examples
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use offset_vec::{delegate_vec_like, Offset};
+///
+/// #[derive(Debug, Default)]
+/// struct Tokens(Vec<&'static str>);
+/// delegate_vec_like!(Tokens => 0: Vec<&'static str>);
+///
+/// let mut tokens = Tokens::default();
+/// tokens.0.push("fn");
+///
+/// let mut rest = tokens.offset_mut(1);
+/// rest.push("main");
+/// rest.insert(0, "pub");
+/// assert_eq!(rest, ["pub", "main"]);
+/// assert_eq!(tokens.0, ["fn", "pub", "main"]);
+/// ```
+///
+/// Generic newtype, over named field:
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use offset_vec::{delegate_vec_like, Offset};
+///
+/// use offset_vec::{SliceLikeMut, VecLike, VecLikeSolid};
+///
+/// #[derive(Debug)]
+/// struct Stack<V> { items: V }
+/// delegate_vec_like!(impl[V: VecLike + VecLikeSolid + SliceLikeMut] Stack<V> => items: V);
+///
+/// let stack = Stack { items: vec![1, 2, 3] };
+/// let top = stack.offset(2);
+/// assert_eq!(top, [3]);
+/// ```
+///
+/// [`SeqLike`]: crate::SeqLike
+/// [`SliceLike`]: crate::SliceLike
+/// [`SliceLikeMut`]: crate::SliceLikeMut
+/// [`Truncatable`]: crate::Truncatable
+/// [`Growable`]: crate::Growable
+/// [`Drainable`]: crate::Drainable
+/// [`SeqVecLike`]: crate::SeqVecLike
+/// [`VecLikeSolid`]: crate::VecLikeSolid
+/// [`Offset`]: crate::Offset
+/// [`OffsetCheckRef`]: crate::OffsetCheckRef
+/// [`retain`]: crate::Truncatable::retain
+#[macro_export]
+macro_rules! delegate_vec_like {
+    (@SeqLike [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::SeqLike for $ty {
+            type Elem = <$inner as $crate::SeqLike>::Elem;
+
+            #[inline]
+            fn len(&self) -> usize {
+                $crate::SeqLike::len(&self.$field)
+            }
+
+            #[inline]
+            fn is_empty(&self) -> bool {
+                $crate::SeqLike::is_empty(&self.$field)
+            }
+        }
+    };
+    (@SliceLike [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::SliceLike for $ty {
+            type Slice = <$inner as $crate::SliceLike>::Slice;
+
+            fn as_slice(&self) -> &Self::Slice {
+                $crate::SliceLike::as_slice(&self.$field)
+            }
+        }
+    };
+    (@SliceLikeMut [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::SliceLikeMut for $ty {
+            fn as_mut_slice(&mut self) -> &mut Self::Slice {
+                $crate::SliceLikeMut::as_mut_slice(&mut self.$field)
+            }
+        }
+    };
+    (@Truncatable [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::Truncatable for $ty {
+            type ElemRef<'a> = <$inner as $crate::Truncatable>::ElemRef<'a> where Self: 'a;
+
+            fn pop(&mut self) -> ::core::option::Option<Self::Elem> {
+                $crate::Truncatable::pop(&mut self.$field)
+            }
+
+            #[track_caller]
+            fn remove(&mut self, index: usize) -> Self::Elem {
+                $crate::Truncatable::remove(&mut self.$field, index)
+            }
+
+            fn truncate(&mut self, len: usize) {
+                $crate::Truncatable::truncate(&mut self.$field, len);
+            }
+
+            fn clear(&mut self) {
+                $crate::Truncatable::clear(&mut self.$field);
+            }
+
+            fn retain<F>(&mut self, f: F)
+            where F: FnMut(<$inner as $crate::Truncatable>::ElemRef<'_>) -> bool,
+            {
+                $crate::Truncatable::retain(&mut self.$field, f);
+            }
+        }
+    };
+    (@Growable [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::Growable for $ty {
+            type Collection = <$inner as $crate::Growable>::Collection;
+
+            const MAX_CAPACITY: usize = <$inner as $crate::Growable>::MAX_CAPACITY;
+
+            fn as_mut_collection(&mut self) -> &mut Self::Collection {
+                $crate::Growable::as_mut_collection(&mut self.$field)
+            }
+
+            fn capacity(&self) -> usize {
+                $crate::Growable::capacity(&self.$field)
+            }
+
+            #[track_caller]
+            fn push(&mut self, value: Self::Elem) {
+                $crate::Growable::push(&mut self.$field, value);
+            }
+
+            #[track_caller]
+            fn try_push(&mut self, value: Self::Elem)
+                -> ::core::result::Result<(), $crate::CapacityError<Self::Elem>>
+            {
+                $crate::Growable::try_push(&mut self.$field, value)
+            }
+
+            #[track_caller]
+            fn try_insert(&mut self, index: usize, element: Self::Elem)
+                -> ::core::result::Result<(), $crate::CapacityError<Self::Elem>>
+            {
+                $crate::Growable::try_insert(&mut self.$field, index, element)
+            }
+
+            #[track_caller]
+            fn insert(&mut self, index: usize, element: Self::Elem) {
+                $crate::Growable::insert(&mut self.$field, index, element);
+            }
+
+            #[track_caller]
+            fn reserve(&mut self, additional: usize) {
+                $crate::Growable::reserve(&mut self.$field, additional);
+            }
+
+            #[track_caller]
+            fn reserve_exact(&mut self, additional: usize) {
+                $crate::Growable::reserve_exact(&mut self.$field, additional);
+            }
+
+            #[track_caller]
+            fn shrink_to(&mut self, min_capacity: usize) {
+                $crate::Growable::shrink_to(&mut self.$field, min_capacity);
+            }
+
+            #[track_caller]
+            fn shrink_to_fit(&mut self) {
+                $crate::Growable::shrink_to_fit(&mut self.$field);
+            }
+
+            #[track_caller]
+            fn append(&mut self, other: &mut Self::Collection) {
+                $crate::Growable::append(&mut self.$field, other);
+            }
+        }
+    };
+    (@Drainable [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::Drainable for $ty {
+            type Drain<'a> = <$inner as $crate::Drainable>::Drain<'a> where Self: 'a;
+
+            #[track_caller]
+            fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+            where R: ::core::ops::RangeBounds<usize>,
+            {
+                $crate::Drainable::drain(&mut self.$field, range)
+            }
+        }
+    };
+    (@SeqVecLike [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::SeqVecLike for $ty {
+            #[track_caller]
+            fn split_off(&mut self, at: usize) -> Self::Collection {
+                $crate::SeqVecLike::split_off(&mut self.$field, at)
+            }
+
+            #[track_caller]
+            fn resize(&mut self, new_len: usize, value: Self::Elem)
+            where Self::Elem: Clone,
+            {
+                $crate::SeqVecLike::resize(&mut self.$field, new_len, value);
+            }
+
+            #[track_caller]
+            fn resize_with<F>(&mut self, new_len: usize, f: F)
+            where F: FnMut() -> Self::Elem,
+            {
+                $crate::SeqVecLike::resize_with(&mut self.$field, new_len, f);
+            }
+        }
+    };
+    (@VecLikeSolid [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::VecLikeSolid for $ty {
+            #[track_caller]
+            fn swap_remove(&mut self, index: usize) -> Self::Elem {
+                $crate::VecLikeSolid::swap_remove(&mut self.$field, index)
+            }
+
+            fn retain_mut<F>(&mut self, f: F)
+            where F: FnMut(&mut Self::Elem) -> bool,
+            {
+                $crate::VecLikeSolid::retain_mut(&mut self.$field, f);
+            }
+
+            fn pop_if<F>(&mut self, predicate: F) -> ::core::option::Option<Self::Elem>
+            where F: FnOnce(&mut Self::Elem) -> bool,
+            {
+                $crate::VecLikeSolid::pop_if(&mut self.$field, predicate)
+            }
+        }
+    };
+    (@Offset [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::Offset for $ty {
+            type Output = Self;
+            type OutputMut = Self;
+
+            #[track_caller]
+            fn offset(self, i: usize) -> $crate::OffsetVec<Self::Output> {
+                let _ = &$crate::SliceLike::as_slice(&self.$field)[i..];
+                $crate::create(self, i)
+            }
+
+            #[track_caller]
+            fn offset_mut(&mut self, i: usize) -> $crate::OffsetVec<&mut Self::OutputMut> {
+                let _ = &$crate::SliceLike::as_slice(&self.$field)[i..];
+                $crate::create(self, i)
+            }
+        }
+    };
+    (@OffsetCheckRef [$($g:tt)*] $ty:ty, $field:tt, $inner:ty) => {
+        impl<$($g)*> $crate::OffsetCheckRef for $ty {}
+    };
+    (@list $g:tt $ty:ty, $field:tt, $inner:ty; [$($Trait:ident),+ $(,)?]) => {
+        $(
+            $crate::delegate_vec_like!(@$Trait $g $ty, $field, $inner);
+        )+
+    };
+    (@list $g:tt $ty:ty, $field:tt, $inner:ty) => {
+        $crate::delegate_vec_like!(@list $g $ty, $field, $inner; [
+            SeqLike, SliceLike, SliceLikeMut, Truncatable, Growable, Drainable,
+            SeqVecLike, VecLikeSolid, Offset, OffsetCheckRef
+        ]);
+    };
+
+    (impl[$($g:tt)*] $ty:ty => $field:tt: $inner:ty $(; $traits:tt)?) => {
+        $crate::delegate_vec_like!(@list [$($g)*] $ty, $field, $inner $(; $traits)?);
+    };
+    ($ty:ty => $field:tt: $inner:ty $(; $traits:tt)?) => {
+        $crate::delegate_vec_like!(@list [] $ty, $field, $inner $(; $traits)?);
+    };
+}
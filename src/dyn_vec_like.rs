@@ -0,0 +1,401 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::ops::{Bound, RangeBounds};
+
+use crate::{CapacityError, Drainable, Growable, OffsetVec, SeqLike, SeqVecLike, Truncatable};
+
+type Bounds = (Bound<usize>, Bound<usize>);
+
+/// Object safe [`SeqVecLike`], for `dyn DynVecLike<T>` without monomorphizing the backend
+///
+/// Implemented for every [`SeqVecLike`] whose collection is `C`,
+/// and `dyn DynVecLike<T, C>` implements [`SeqVecLike`] in turn,
+/// use the [`SeqVecLike`] methods instead of the `dyn_*` methods.
+///
+/// `C` is the collection of [`split_off`](SeqVecLike::split_off) and [`append`](Growable::append),
+/// e.g `Vec<T>` of [`Vec`], [`Rc<Vec<T>>`](alloc::rc::Rc) and [`Cow<[T]>`](alloc::borrow::Cow)
+///
+/// Callbacks are `&mut dyn FnMut` and [`drain`](Drainable::drain) is boxed,
+/// [`retain`](Truncatable::retain) drains and pushes back the kept elements,
+/// a panic of the callback keeps the unprocessed elements like [`Vec::retain`].
+/// Windows of `&mut dyn DynVecLike` retain by [`dyn_retain_from`](DynVecLike::dyn_retain_from)
+///
+/// # Memory
+///
+/// [`retain`](Truncatable::retain) buffers the retained range in a temporary [`Vec`],
+/// O(n) extra memory even for disk backed backends like `FileVec`, and a shared
+/// [`Rc`](alloc::rc::Rc) or [`Arc`](alloc::sync::Arc) clones its buffer first.
+/// Use [`VecLikeSolid::retain_mut`](crate::VecLikeSolid::retain_mut) on the concrete backend
+/// to retain in place
+///
+/// # Examples
+///
+/// ```
+/// use offset_vec::{DynVecLike, Offset};
+///
+/// fn plugin(buf: &mut dyn DynVecLike<u8>) {
+///     buf.retain(|&b| b != b' ');
+///     let mut body = buf.offset(1);
+///     body.insert(0, b'<');
+///     body.push(b'>');
+/// }
+///
+/// let mut vec = b"#a b".to_vec();
+/// plugin(&mut vec);
+/// assert_eq!(vec, b"#<ab>");
+///
+/// let mut rc = std::rc::Rc::new(b"#c d".to_vec());
+/// plugin(&mut rc);
+/// assert_eq!(*rc, b"#<cd>");
+/// ```
+pub trait DynVecLike<T, C = Vec<T>> {
+    fn dyn_len(&self) -> usize;
+
+    fn dyn_is_empty(&self) -> bool;
+
+    fn dyn_capacity(&self) -> usize;
+
+    fn dyn_reserve(&mut self, additional: usize);
+
+    fn dyn_reserve_exact(&mut self, additional: usize);
+
+    fn dyn_shrink_to_fit(&mut self);
+
+    fn dyn_shrink_to(&mut self, min_capacity: usize);
+
+    fn dyn_push(&mut self, value: T);
+
+    fn dyn_try_push(&mut self, value: T) -> Result<(), CapacityError<T>>;
+
+    fn dyn_insert(&mut self, index: usize, element: T);
+
+    fn dyn_try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>>;
+
+    fn dyn_pop(&mut self) -> Option<T>;
+
+    fn dyn_remove(&mut self, index: usize) -> T;
+
+    fn dyn_truncate(&mut self, len: usize);
+
+    fn dyn_clear(&mut self);
+
+    fn dyn_retain(&mut self, f: &mut dyn FnMut(&T) -> bool);
+
+    /// Like [`dyn_retain`](DynVecLike::dyn_retain), but keeps the elements before `start`
+    fn dyn_retain_from(&mut self, start: usize, f: &mut dyn FnMut(&T) -> bool);
+
+    fn dyn_drain(&mut self, range: Bounds) -> Box<dyn Iterator<Item = T> + '_>;
+
+    fn dyn_as_mut_collection(&mut self) -> &mut C;
+
+    fn dyn_append(&mut self, other: &mut C);
+
+    fn dyn_split_off(&mut self, at: usize) -> C;
+
+    fn dyn_resize_with(&mut self, new_len: usize, f: &mut dyn FnMut() -> T);
+}
+
+impl<V: SeqVecLike> DynVecLike<V::Elem, V::Collection> for V {
+    fn dyn_len(&self) -> usize {
+        self.len()
+    }
+
+    fn dyn_is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn dyn_capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn dyn_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    #[track_caller]
+    fn dyn_reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+
+    #[track_caller]
+    fn dyn_shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    #[track_caller]
+    fn dyn_shrink_to(&mut self, min_capacity: usize) {
+        self.shrink_to(min_capacity);
+    }
+
+    #[track_caller]
+    fn dyn_push(&mut self, value: V::Elem) {
+        self.push(value);
+    }
+
+    #[track_caller]
+    fn dyn_try_push(&mut self, value: V::Elem) -> Result<(), CapacityError<V::Elem>> {
+        self.try_push(value)
+    }
+
+    #[track_caller]
+    fn dyn_insert(&mut self, index: usize, element: V::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn dyn_try_insert(&mut self, index: usize, element: V::Elem) -> Result<(), CapacityError<V::Elem>> {
+        self.try_insert(index, element)
+    }
+
+    fn dyn_pop(&mut self) -> Option<V::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn dyn_remove(&mut self, index: usize) -> V::Elem {
+        self.remove(index)
+    }
+
+    fn dyn_truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn dyn_clear(&mut self) {
+        self.clear();
+    }
+
+    fn dyn_retain(&mut self, f: &mut dyn FnMut(&V::Elem) -> bool) {
+        self.dyn_retain_from(0, f);
+    }
+
+    #[track_caller]
+    fn dyn_retain_from(&mut self, start: usize, f: &mut dyn FnMut(&V::Elem) -> bool) {
+        /// Push back the unprocessed elements, when `f` panics
+        struct Guard<'a, V: SeqVecLike> {
+            vec: &'a mut V,
+            rest: alloc::vec::IntoIter<V::Elem>,
+        }
+        impl<V: SeqVecLike> Drop for Guard<'_, V> {
+            fn drop(&mut self) {
+                for elem in &mut self.rest {
+                    self.vec.push(elem);
+                }
+            }
+        }
+
+        let elems: Vec<_> = self.drain(start..).collect();
+        let mut guard = Guard { vec: self, rest: elems.into_iter() };
+        while let [elem, ..] = guard.rest.as_slice() {
+            let keep = f(elem);
+            let elem = guard.rest.next().unwrap();
+            if keep {
+                guard.vec.push(elem);
+            }
+        }
+    }
+
+    #[track_caller]
+    fn dyn_drain(&mut self, range: Bounds) -> Box<dyn Iterator<Item = V::Elem> + '_> {
+        Box::new(self.drain(range))
+    }
+
+    fn dyn_as_mut_collection(&mut self) -> &mut V::Collection {
+        self.as_mut_collection()
+    }
+
+    #[track_caller]
+    fn dyn_append(&mut self, other: &mut V::Collection) {
+        self.append(other);
+    }
+
+    #[track_caller]
+    fn dyn_split_off(&mut self, at: usize) -> V::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn dyn_resize_with(&mut self, new_len: usize, f: &mut dyn FnMut() -> V::Elem) {
+        self.resize_with(new_len, f);
+    }
+}
+
+impl<T, C> dyn DynVecLike<T, C> + '_ {
+    /// Like [`Truncatable::retain`], which needs `'static` trait objects
+    pub fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.dyn_retain(&mut f);
+    }
+}
+
+impl<T, C> OffsetVec<&mut (dyn DynVecLike<T, C> + '_)> {
+    /// Like [`OffsetVec::retain`], `dyn DynVecLike` is not [`SliceLike`](crate::SliceLike)
+    pub fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.vec.dyn_retain_from(self.offset, &mut f);
+    }
+}
+
+impl<T, C> SeqLike for dyn DynVecLike<T, C> + '_ {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.dyn_len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.dyn_is_empty()
+    }
+}
+
+impl<T, C> Truncatable for dyn DynVecLike<T, C> + '_ {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<T> {
+        self.dyn_pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> T {
+        self.dyn_remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.dyn_truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.dyn_clear();
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.dyn_retain(&mut f);
+    }
+}
+
+impl<T, C> Growable for dyn DynVecLike<T, C> + '_ {
+    type Collection = C;
+
+    fn as_mut_collection(&mut self) -> &mut C {
+        self.dyn_as_mut_collection()
+    }
+
+    fn capacity(&self) -> usize {
+        self.dyn_capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: T) {
+        self.dyn_push(value);
+    }
+
+    #[track_caller]
+    fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.dyn_try_push(value)
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.dyn_try_insert(index, element)
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: T) {
+        self.dyn_insert(index, element);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.dyn_reserve(additional);
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.dyn_reserve_exact(additional);
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.dyn_shrink_to(min_capacity);
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.dyn_shrink_to_fit();
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut C) {
+        self.dyn_append(other);
+    }
+}
+
+impl<T, C> Drainable for dyn DynVecLike<T, C> + '_ {
+    type Drain<'a> = Box<dyn Iterator<Item = T> + 'a> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.dyn_drain((range.start_bound().cloned(), range.end_bound().cloned()))
+    }
+}
+
+impl<T, C> SeqVecLike for dyn DynVecLike<T, C> + '_ {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> C {
+        self.dyn_split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: T)
+    where T: Clone,
+    {
+        self.dyn_resize_with(new_len, &mut || value.clone());
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where F: FnMut() -> T,
+    {
+        self.dyn_resize_with(new_len, &mut f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec};
+    use super::*;
+
+    #[test]
+    fn erased_string() {
+        let mut s = String::from("你好");
+        let buf: &mut dyn DynVecLike<char, String> = &mut s;
+        buf.push('!');
+        buf.retain(|&ch| ch != '你');
+        let tail = buf.split_off(3);
+        assert_eq!(tail, "!");
+        assert_eq!(buf.drain(..).collect::<String>(), "好");
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    fn erased_box() {
+        let mut bufs: Vec<Box<dyn DynVecLike<i32>>> = vec![
+            Box::new(vec![1, 2]),
+            Box::new(alloc::rc::Rc::new(vec![3])),
+        ];
+        for buf in &mut bufs {
+            buf.resize(3, 0);
+            buf.insert(0, 9);
+        }
+        assert_eq!(bufs[0].drain(..).collect::<Vec<_>>(), [9, 1, 2, 0]);
+        assert_eq!(bufs[1].drain(1..).collect::<Vec<_>>(), [3, 0, 0]);
+        assert_eq!(bufs[1].len(), 1);
+    }
+}
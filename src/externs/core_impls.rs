@@ -1,67 +1,70 @@
-use core::{borrow::{Borrow, BorrowMut}, hash::Hash};
+use core::{borrow::{Borrow, BorrowMut}, fmt, hash::Hash};
 
-use crate::{create, OffsetVec, VecLike};
+use crate::{create, Growable, OffsetVec, SeqLike, SliceLike, SliceLikeMut, SliceMut, Truncatable};
 
-impl<V: VecLike> PartialEq for OffsetVec<V> where V::Slice: PartialEq {
+impl<V: SliceLike> PartialEq for OffsetVec<V> where V::Slice: PartialEq {
     fn eq(&self, other: &Self) -> bool {
         **self == **other
     }
 }
 
-impl<V: VecLike> PartialEq<V> for OffsetVec<V> where V::Slice: PartialEq {
+impl<V: SliceLike> PartialEq<V> for OffsetVec<V> where V::Slice: PartialEq {
     fn eq(&self, other: &V) -> bool {
         **self == *other.as_slice()
     }
 }
 
-impl<V: VecLike> Eq for OffsetVec<V> where V::Slice: Eq { }
+impl<V: SliceLike> Eq for OffsetVec<V> where V::Slice: Eq { }
 
-impl<V: VecLike> PartialOrd for OffsetVec<V> where V::Slice: PartialOrd {
+impl<V: SliceLike> PartialOrd for OffsetVec<V> where V::Slice: PartialOrd {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         (**self).partial_cmp(&**other)
     }
 }
 
-impl<V: VecLike> Ord for OffsetVec<V> where V::Slice: Ord {
+impl<V: SliceLike> Ord for OffsetVec<V> where V::Slice: Ord {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         (**self).cmp(&**other)
     }
 }
 
-impl<T: PartialEq, V: VecLike<Elem = T, Slice = [T]>> PartialEq<[T]> for OffsetVec<V> {
+impl<T: PartialEq, V: SliceLike<Elem = T, Slice = [T]>> PartialEq<[T]> for OffsetVec<V> {
     fn eq(&self, other: &[V::Elem]) -> bool {
         **self == *other
     }
 }
-impl<T: PartialEq, V: VecLike<Elem = T, Slice = [T]>> PartialEq<&[T]> for OffsetVec<V> {
+// `Truncatable` excludes `V = &[T]`, which overlaps `PartialEq<V>`
+impl<T: PartialEq, V: SliceLike<Elem = T, Slice = [T]> + Truncatable> PartialEq<&[T]> for OffsetVec<V> {
     fn eq(&self, other: &&[V::Elem]) -> bool {
         **self == **other
     }
 }
 
-impl<T: PartialEq, V: VecLike<Elem = T, Slice = [T]>, const N: usize> PartialEq<[T; N]> for OffsetVec<V> {
+// `Truncatable` excludes `V = [T; N]` and `V = &[T; N]`, which overlap `PartialEq<V>`
+impl<T: PartialEq, V: SliceLike<Elem = T, Slice = [T]> + Truncatable, const N: usize> PartialEq<[T; N]> for OffsetVec<V> {
     fn eq(&self, other: &[V::Elem; N]) -> bool {
         **self == *other
     }
 }
-impl<T: PartialEq, V: VecLike<Elem = T, Slice = [T]>, const N: usize> PartialEq<&[T; N]> for OffsetVec<V> {
+impl<T: PartialEq, V: SliceLike<Elem = T, Slice = [T]> + Truncatable, const N: usize> PartialEq<&[T; N]> for OffsetVec<V> {
     fn eq(&self, other: &&[V::Elem; N]) -> bool {
         **self == **other
     }
 }
 
-impl<V: VecLike<Slice = str>> PartialEq<str> for OffsetVec<V> {
+impl<V: SliceLike<Slice = str>> PartialEq<str> for OffsetVec<V> {
     fn eq(&self, other: &str) -> bool {
         **self == *other
     }
 }
-impl<V: VecLike<Slice = str>> PartialEq<&str> for OffsetVec<V> {
+// `Truncatable` excludes `V = &str`, which overlaps `PartialEq<V>`
+impl<V: SliceLike<Slice = str> + Truncatable> PartialEq<&str> for OffsetVec<V> {
     fn eq(&self, other: &&str) -> bool {
         **self == **other
     }
 }
 
-impl<V: VecLike + IntoIterator> IntoIterator for OffsetVec<V> {
+impl<V: SliceLike + IntoIterator> IntoIterator for OffsetVec<V> {
     type Item = V::Item;
     type IntoIter = V::IntoIter;
 
@@ -76,7 +79,7 @@ impl<V: VecLike + IntoIterator> IntoIterator for OffsetVec<V> {
     }
 }
 
-impl<'a, V: VecLike + IntoIterator> IntoIterator for &'a OffsetVec<V> where &'a V::Slice: IntoIterator {
+impl<'a, V: SliceLike + IntoIterator> IntoIterator for &'a OffsetVec<V> where &'a V::Slice: IntoIterator {
     type Item = <&'a V::Slice as IntoIterator>::Item;
     type IntoIter = <&'a V::Slice as IntoIterator>::IntoIter;
 
@@ -85,7 +88,10 @@ impl<'a, V: VecLike + IntoIterator> IntoIterator for &'a OffsetVec<V> where &'a
     }
 }
 
-impl<'a, V: VecLike + IntoIterator> IntoIterator for &'a mut OffsetVec<V> where &'a mut V::Slice: IntoIterator {
+impl<'a, V: SliceLikeMut + IntoIterator> IntoIterator for &'a mut OffsetVec<V>
+where &'a mut V::Slice: IntoIterator,
+      V::Slice: SliceMut,
+{
     type Item = <&'a mut V::Slice as IntoIterator>::Item;
     type IntoIter = <&'a mut V::Slice as IntoIterator>::IntoIter;
 
@@ -94,59 +100,65 @@ impl<'a, V: VecLike + IntoIterator> IntoIterator for &'a mut OffsetVec<V> where
     }
 }
 
-impl<T, V: VecLike<Slice = [T]>> Borrow<[T]> for OffsetVec<V> {
+impl<T, V: SliceLike<Slice = [T]>> Borrow<[T]> for OffsetVec<V> {
     fn borrow(&self) -> &V::Slice {
         self
     }
 }
-impl<T, V: VecLike<Slice = [T]>> BorrowMut<[T]> for OffsetVec<V> {
+impl<T, V: SliceLikeMut<Slice = [T]>> BorrowMut<[T]> for OffsetVec<V> {
     fn borrow_mut(&mut self) -> &mut V::Slice {
         self
     }
 }
 
-impl<V: VecLike<Slice = str>> Borrow<str> for OffsetVec<V> {
+impl<V: SliceLike<Slice = str>> Borrow<str> for OffsetVec<V> {
     fn borrow(&self) -> &V::Slice {
         self
     }
 }
-impl<V: VecLike<Slice = str>> BorrowMut<str> for OffsetVec<V> {
+impl<V: SliceLikeMut<Slice = str>> BorrowMut<str> for OffsetVec<V> {
     fn borrow_mut(&mut self) -> &mut V::Slice {
         self
     }
 }
 
-impl<V: VecLike> Hash for OffsetVec<V> where V::Slice: Hash {
+impl<V: SliceLike> Hash for OffsetVec<V> where V::Slice: Hash {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_slice().hash(state);
     }
 }
 
-impl<V: VecLike> AsRef<V::Slice> for OffsetVec<V> {
+impl<V: SliceLike> fmt::Display for OffsetVec<V> where V::Slice: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<V: SliceLike> AsRef<V::Slice> for OffsetVec<V> {
     fn as_ref(&self) -> &V::Slice {
         self
     }
 }
 
-impl<V: VecLike> AsMut<V::Slice> for OffsetVec<V> {
+impl<V: SliceLikeMut> AsMut<V::Slice> for OffsetVec<V> where V::Slice: SliceMut {
     fn as_mut(&mut self) -> &mut V::Slice {
         self
     }
 }
 
-impl<V: VecLike> From<V> for OffsetVec<V> {
+impl<V: SeqLike> From<V> for OffsetVec<V> {
     fn from(value: V) -> Self {
         create(value, 0)
     }
 }
 
-impl<T, V: VecLike> Extend<T> for OffsetVec<V> where V::Collection: Extend<T> {
+impl<T, V: Growable> Extend<T> for OffsetVec<V> where V::Collection: Extend<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         self.vec.as_mut_collection().extend(iter);
     }
 }
 
-impl<T, V: VecLike> FromIterator<T> for OffsetVec<V> where V: FromIterator<T> {
+impl<T, V: SeqLike> FromIterator<T> for OffsetVec<V> where V: FromIterator<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         iter.into_iter().collect::<V>().into()
     }
@@ -0,0 +1,535 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::{self, size_of, size_of_val},
+    ops::Range,
+    slice,
+};
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    vec,
+    vec::Vec,
+};
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+mod mmap;
+
+#[cfg(feature = "memmap2")]
+pub use mmap::MmapVec;
+
+/// Element stored as raw native-endian bytes in [`FileVec`]
+///
+/// # Safety
+/// - Any bit pattern is a valid value, e.g the zeros of a grown file
+/// - No padding bytes
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            // SAFETY: primitive numbers have no padding and no invalid values
+            unsafe impl Pod for $t {}
+        )+
+    };
+}
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// SAFETY: array elements are Pod and have no padding between them
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+const CHUNK_BYTES: usize = 8 * 1024;
+
+fn as_bytes<T: Pod>(data: &[T]) -> &[u8] {
+    // SAFETY: Pod has no padding, all bytes are initialized
+    unsafe { slice::from_raw_parts(data.as_ptr().cast(), size_of_val(data)) }
+}
+
+fn as_bytes_mut<T: Pod>(data: &mut [T]) -> &mut [u8] {
+    // SAFETY: Pod has no padding and accepts any bit pattern
+    unsafe { slice::from_raw_parts_mut(data.as_mut_ptr().cast(), size_of_val(data)) }
+}
+
+pub(crate) fn zeroed<T: Pod>() -> T {
+    // SAFETY: Pod accepts any bit pattern
+    unsafe { mem::zeroed() }
+}
+
+pub(crate) fn chunk_buf<T: Pod>() -> Vec<T> {
+    vec![zeroed(); (CHUNK_BYTES / size_of::<T>()).max(1)]
+}
+
+#[track_caller]
+fn elem_size<T>() -> usize {
+    let size = size_of::<T>();
+    assert_ne!(size, 0, "zero-sized elements cannot be stored in a file");
+    size
+}
+
+#[track_caller]
+fn file_len<T>(capacity: usize) -> u64 {
+    capacity.checked_mul(elem_size::<T>())
+        .and_then(|len| u64::try_from(len).ok())
+        .expect("capacity overflow")
+}
+
+fn elem_count<T>(file: &File) -> io::Result<usize> {
+    let len = usize::try_from(file.metadata()?.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "file too large"))?;
+    if !len.is_multiple_of(elem_size::<T>()) {
+        let msg = "file length is not a multiple of the element size";
+        return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+    }
+    Ok(len / elem_size::<T>())
+}
+
+fn open_options() -> OpenOptions {
+    let mut options = OpenOptions::new();
+    options.read(true).write(true).create(true).truncate(false);
+    options
+}
+
+fn grown_capacity(len: usize, cap: usize, additional: usize) -> io::Result<Option<usize>> {
+    let required = len.checked_add(additional)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::OutOfMemory, "capacity overflow"))?;
+    Ok((required > cap).then(|| required.max(cap.saturating_mul(2)).max(8)))
+}
+
+#[track_caller]
+fn check_range(index: usize, count: usize, len: usize) {
+    if index.checked_add(count).is_none_or(|end| end > len) {
+        #[cold]
+        #[track_caller]
+        #[inline(never)]
+        fn fail(index: usize, count: usize, len: usize) -> ! {
+            panic!("range {index}..{index}+{count} out of length (is {len})");
+        }
+        fail(index, count, len)
+    }
+}
+
+/// Positional read, the file cursor is not shared, so concurrent reads through `&self` do not race
+#[cfg(unix)]
+fn read_exact_at(file: &File, buf: &mut [u8], offset: u64) -> io::Result<()> {
+    std::os::unix::fs::FileExt::read_exact_at(file, buf, offset)
+}
+
+#[cfg(unix)]
+fn write_all_at(file: &File, data: &[u8], offset: u64) -> io::Result<()> {
+    std::os::unix::fs::FileExt::write_all_at(file, data, offset)
+}
+
+#[cfg(windows)]
+fn read_exact_at(file: &File, mut buf: &mut [u8], mut offset: u64) -> io::Result<()> {
+    use std::os::windows::fs::FileExt;
+
+    while !buf.is_empty() {
+        match file.seek_read(buf, offset) {
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => {
+                buf = &mut buf[n..];
+                offset += n as u64;
+            },
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn write_all_at(file: &File, mut data: &[u8], mut offset: u64) -> io::Result<()> {
+    use std::os::windows::fs::FileExt;
+
+    while !data.is_empty() {
+        match file.seek_write(data, offset) {
+            Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+            Ok(n) => {
+                data = &data[n..];
+                offset += n as u64;
+            },
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+/// Fallback on the shared cursor, [`Marker`] makes [`FileVec`] not [`Sync`] there
+#[cfg(not(any(unix, windows)))]
+fn read_exact_at(mut file: &File, buf: &mut [u8], offset: u64) -> io::Result<()> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_exact(buf)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn write_all_at(mut file: &File, data: &[u8], offset: u64) -> io::Result<()> {
+    use std::io::{Seek, SeekFrom};
+
+    file.seek(SeekFrom::Start(offset))?;
+    file.write_all(data)
+}
+
+#[cfg(any(unix, windows))]
+type Marker<T> = PhantomData<T>;
+#[cfg(not(any(unix, windows)))]
+type Marker<T> = PhantomData<(T, core::cell::Cell<()>)>;
+
+#[cold]
+#[track_caller]
+#[inline(never)]
+pub(crate) fn io_failed(err: io::Error) -> ! {
+    panic!("file-backed buffer I/O failed: {err}");
+}
+
+/// Growable buffer stored in a local file, elements are read and written on demand
+///
+/// The file length is the capacity, [`reserve`] grows it by [`File::set_len`].
+/// The spare capacity is trimmed by [`try_shrink_to_fit`], [`close`] and drop,
+/// so reopening the file sees exactly the elements,
+/// [`flush`] and [`sync`] keep it for the next growth
+///
+/// Methods of [`SeqVecLike`](crate::SeqVecLike) panic on I/O errors,
+/// use the inherent `try_*` methods to handle them
+///
+/// # Examples
+///
+/// ```
+/// use offset_vec::{FileVec, Offset};
+///
+/// let mut journal = FileVec::<u32>::temp()?;
+/// journal.extend_from_slice(&[1, 2])?;
+///
+/// let mut tail = journal.offset_mut(2);
+/// tail.extend([3, 4]);
+/// assert_eq!(tail.len(), 2);
+///
+/// let mut buf = [0; 4];
+/// journal.read_at(0, &mut buf)?;
+/// assert_eq!(buf, [1, 2, 3, 4]);
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// [`reserve`]: FileVec::try_reserve
+/// [`try_shrink_to_fit`]: FileVec::try_shrink_to_fit
+/// [`close`]: FileVec::close
+/// [`flush`]: FileVec::flush
+/// [`sync`]: FileVec::sync
+pub struct FileVec<T: Pod = u8> {
+    file: File,
+    len: usize,
+    cap: usize,
+    marker: Marker<T>,
+}
+
+impl<T: Pod> FileVec<T> {
+    /// Open or create the file, existing contents become the elements
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_file(open_options().open(path)?)
+    }
+
+    /// Create on an anonymous temporary file, removed when closed
+    pub fn temp() -> io::Result<Self> {
+        Self::from_file(tempfile::tempfile()?)
+    }
+
+    /// Existing contents become the elements, the file must be readable and writable
+    pub fn from_file(file: File) -> io::Result<Self> {
+        let len = elem_count::<T>(&file)?;
+        Ok(Self { file, len, cap: len, marker: PhantomData })
+    }
+
+    #[inline]
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    pub(crate) fn read_raw(&self, index: usize, buf: &mut [T]) -> io::Result<()> {
+        read_exact_at(&self.file, as_bytes_mut(buf), file_len::<T>(index))
+    }
+
+    pub(crate) fn write_raw(&self, index: usize, data: &[T]) -> io::Result<()> {
+        write_all_at(&self.file, as_bytes(data), file_len::<T>(index))
+    }
+
+    /// Read elements start at `index`, fill the `buf`
+    ///
+    /// # Panics
+    /// - `index + buf.len()` out of length
+    #[track_caller]
+    pub fn read_at(&self, index: usize, buf: &mut [T]) -> io::Result<()> {
+        check_range(index, buf.len(), self.len);
+        self.read_raw(index, buf)
+    }
+
+    /// Overwrite elements start at `index`
+    ///
+    /// # Panics
+    /// - `index + data.len()` out of length
+    #[track_caller]
+    pub fn write_at(&mut self, index: usize, data: &[T]) -> io::Result<()> {
+        check_range(index, data.len(), self.len);
+        self.write_raw(index, data)
+    }
+
+    /// Read a element, return [`None`] if `index` out of length
+    pub fn get(&self, index: usize) -> io::Result<Option<T>> {
+        if index >= self.len {
+            return Ok(None);
+        }
+        let mut elem = [zeroed()];
+        self.read_raw(index, &mut elem)?;
+        Ok(Some(elem[0]))
+    }
+
+    pub fn extend_from_slice(&mut self, data: &[T]) -> io::Result<()> {
+        self.try_reserve(data.len())?;
+        self.write_raw(self.len, data)?;
+        self.len += data.len();
+        Ok(())
+    }
+
+    pub(crate) fn set_capacity(&mut self, capacity: usize) -> io::Result<()> {
+        debug_assert!(capacity >= self.len);
+        self.file.set_len(file_len::<T>(capacity))?;
+        self.cap = capacity;
+        Ok(())
+    }
+
+    /// Grow the file for at least `additional` more elements, the new space reads as zeros
+    pub fn try_reserve(&mut self, additional: usize) -> io::Result<()> {
+        match grown_capacity(self.len, self.cap, additional)? {
+            Some(capacity) => self.set_capacity(capacity),
+            None => Ok(()),
+        }
+    }
+
+    pub fn try_reserve_exact(&mut self, additional: usize) -> io::Result<()> {
+        match grown_capacity(self.len, self.cap, additional)? {
+            Some(_) => self.set_capacity(self.len + additional),
+            None => Ok(()),
+        }
+    }
+
+    /// Trim the file to the length
+    pub fn try_shrink_to_fit(&mut self) -> io::Result<()> {
+        if self.cap == self.len {
+            return Ok(());
+        }
+        self.set_capacity(self.len)
+    }
+
+    /// Flush the written elements, the spare capacity is kept
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+
+    /// Like [`flush`](Self::flush), and wait until the contents reach the disk
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.flush()?;
+        self.file.sync_all()
+    }
+
+    /// Trim the spare capacity and [`sync`](Self::sync),
+    /// reports the errors that drop ignores
+    pub fn close(mut self) -> io::Result<()> {
+        self.try_shrink_to_fit()?;
+        self.sync()
+    }
+
+    /// Move the elements of `src` to start at `dest`, like [`slice::copy_within`]
+    pub(crate) fn copy_within(&self, src: Range<usize>, dest: usize) -> io::Result<()> {
+        let mut buf = chunk_buf::<T>();
+        let step = buf.len();
+
+        if dest <= src.start {
+            let mut i = src.start;
+            while i < src.end {
+                let n = step.min(src.end - i);
+                self.read_raw(i, &mut buf[..n])?;
+                self.write_raw(dest + (i - src.start), &buf[..n])?;
+                i += n;
+            }
+        } else {
+            let mut end = src.end;
+            while end > src.start {
+                let n = step.min(end - src.start);
+                let i = end - n;
+                self.read_raw(i, &mut buf[..n])?;
+                self.write_raw(dest + (i - src.start), &buf[..n])?;
+                end = i;
+            }
+        }
+        Ok(())
+    }
+
+    /// Keep the elements that `f` returns `true`, `f` can modify the elements
+    pub(crate) fn try_retain_mut<F>(&mut self, mut f: F) -> io::Result<()>
+    where F: FnMut(&mut T) -> bool,
+    {
+        /// Write back the kept elements and shift down the unread tail,
+        /// when `f` panics or an I/O error returns
+        struct Guard<'a, T: Pod> {
+            vec: &'a mut FileVec<T>,
+            buf: Vec<T>,
+            kept: usize,
+            read: usize,
+            write: usize,
+        }
+        impl<T: Pod> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                let Self { ref mut vec, ref buf, kept, read, write } = *self;
+                let len = vec.len;
+                let moved = vec.write_raw(write, &buf[..kept])
+                    .and_then(|()| vec.copy_within(read..len, write + kept));
+                vec.len = match moved {
+                    Ok(()) => write + kept + (len - read),
+                    Err(_) => write,
+                };
+            }
+        }
+
+        let buf = chunk_buf::<T>();
+        let step = buf.len();
+        let mut guard = Guard { vec: self, buf, kept: 0, read: 0, write: 0 };
+
+        while guard.read < guard.vec.len {
+            let n = step.min(guard.vec.len - guard.read);
+            guard.vec.read_raw(guard.read, &mut guard.buf[..n])?;
+
+            for i in 0..n {
+                let mut elem = guard.buf[i];
+                let keep = f(&mut elem);
+                guard.read += 1;
+                if keep {
+                    guard.buf[guard.kept] = elem;
+                    guard.kept += 1;
+                }
+            }
+            guard.vec.write_raw(guard.write, &guard.buf[..guard.kept])?;
+            guard.write += guard.kept;
+            guard.kept = 0;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.cap);
+        self.len = new_len;
+    }
+}
+
+impl<T: Pod> Drop for FileVec<T> {
+    fn drop(&mut self) {
+        let _ = self.try_shrink_to_fit();
+    }
+}
+
+impl<T: Pod> fmt::Debug for FileVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileVec")
+            .field("file", &self.file)
+            .field("len", &self.len)
+            .field("cap", &self.cap)
+            .finish()
+    }
+}
+
+impl<T: Pod> Extend<T> for FileVec<T> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut buf = Vec::with_capacity(chunk_buf::<T>().len());
+        for elem in iter {
+            buf.push(elem);
+            if buf.len() == buf.capacity() {
+                self.extend_from_slice(&buf).unwrap_or_else(|e| io_failed(e));
+                buf.clear();
+            }
+        }
+        self.extend_from_slice(&buf).unwrap_or_else(|e| io_failed(e));
+    }
+}
+impl<'a, T: Pod> Extend<&'a T> for FileVec<T> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+
+        let mut vec = FileVec::<u16>::open(&path).unwrap();
+        vec.extend(0..5000);
+        assert!(vec.capacity() >= 5000);
+        vec.copy_within(4000..5000, 1).unwrap();
+        vec.set_len(1001);
+        drop(vec);
+
+        let vec = FileVec::<u16>::open(&path).unwrap();
+        assert_eq!(vec.len(), 1001);
+        assert_eq!(vec.capacity(), 1001);
+        let mut buf = [0; 3];
+        vec.read_at(999, &mut buf[..2]).unwrap();
+        assert_eq!(buf, [4998, 4999, 0]);
+        assert_eq!(vec.get(0).unwrap(), Some(0));
+        assert_eq!(vec.get(1001).unwrap(), None);
+    }
+
+    #[test]
+    fn shared_reads() {
+        let mut vec = FileVec::<u32>::temp().unwrap();
+        vec.extend(0..1000);
+        std::thread::scope(|s| {
+            for t in 0..4 {
+                let vec = &vec;
+                s.spawn(move || {
+                    for i in (t..1000).step_by(4) {
+                        assert_eq!(vec.get(i).unwrap(), Some(i as u32));
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn bad_length() {
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&[0; 3]).unwrap();
+        let err = FileVec::<u16>::from_file(file).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    #[should_panic = "out of length"]
+    fn write_out_of_length() {
+        let mut vec = FileVec::<u8>::temp().unwrap();
+        vec.extend_from_slice(b"ab").unwrap();
+        let _ = vec.write_at(1, b"cd");
+    }
+}
@@ -0,0 +1,271 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    slice,
+};
+use std::{fs::File, io, path::Path};
+
+use memmap2::MmapMut;
+
+use super::{elem_count, file_len, grown_capacity, open_options, Pod};
+
+/// Growable buffer memory-mapped from a local file, derefs to `[T]`
+///
+/// Like [`FileVec`](super::FileVec), the file length is the capacity,
+/// and the spare capacity is trimmed by [`try_shrink_to_fit`], [`close`] and drop.
+/// Growing the capacity remaps the file
+///
+/// # Examples
+///
+/// ```
+/// use offset_vec::{MmapVec, Offset};
+///
+/// let mut journal = MmapVec::<u8>::temp()?;
+/// journal.extend_from_slice(b"head:")?;
+///
+/// let mut body = journal.offset_mut(5);
+/// body.extend(b"body");
+/// assert_eq!(body, *b"body");
+///
+/// assert_eq!(&journal[..], b"head:body");
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// [`try_shrink_to_fit`]: MmapVec::try_shrink_to_fit
+/// [`close`]: MmapVec::close
+pub struct MmapVec<T: Pod = u8> {
+    file: File,
+    map: Option<MmapMut>,
+    len: usize,
+    cap: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: Pod> MmapVec<T> {
+    /// Open or create the file and map it, existing contents become the elements
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by others while mapped,
+    /// see [`MmapMut::map_mut`]
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        // SAFETY: guaranteed by the caller
+        unsafe { Self::from_file(open_options().open(path)?) }
+    }
+
+    /// Create on an anonymous temporary file, removed when closed
+    pub fn temp() -> io::Result<Self> {
+        // SAFETY: the anonymous file is not reachable by others
+        unsafe { Self::from_file(tempfile::tempfile()?) }
+    }
+
+    /// Map the file, existing contents become the elements
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by others while mapped,
+    /// see [`MmapMut::map_mut`]
+    pub unsafe fn from_file(file: File) -> io::Result<Self> {
+        let len = elem_count::<T>(&file)?;
+        let mut this = Self { file, map: None, len, cap: len, marker: PhantomData };
+        this.remap()?;
+        Ok(this)
+    }
+
+    fn remap(&mut self) -> io::Result<()> {
+        self.map = None;
+        if self.cap != 0 {
+            // SAFETY: guaranteed by the constructor
+            match unsafe { MmapMut::map_mut(&self.file) } {
+                Ok(map) => self.map = Some(map),
+                Err(e) => {
+                    self.len = 0;
+                    self.cap = 0;
+                    return Err(e);
+                },
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        let Some(map) = &self.map else { return &[] };
+        // SAFETY: the mapping is page aligned, and 0..len is inside of the mapping
+        unsafe { slice::from_raw_parts(map.as_ptr().cast(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let Some(map) = &mut self.map else { return &mut [] };
+        // SAFETY: the mapping is page aligned, and 0..len is inside of the mapping
+        unsafe { slice::from_raw_parts_mut(map.as_mut_ptr().cast(), self.len) }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    pub(crate) fn set_capacity(&mut self, capacity: usize) -> io::Result<()> {
+        debug_assert!(capacity >= self.len);
+        self.map = None;
+        let result = self.file.set_len(file_len::<T>(capacity));
+        if result.is_ok() {
+            self.cap = capacity;
+        }
+        self.remap()?;
+        result
+    }
+
+    /// Grow and remap the file for at least `additional` more elements,
+    /// the new space reads as zeros
+    pub fn try_reserve(&mut self, additional: usize) -> io::Result<()> {
+        match grown_capacity(self.len, self.cap, additional)? {
+            Some(capacity) => self.set_capacity(capacity),
+            None => Ok(()),
+        }
+    }
+
+    pub fn try_reserve_exact(&mut self, additional: usize) -> io::Result<()> {
+        match grown_capacity(self.len, self.cap, additional)? {
+            Some(_) => self.set_capacity(self.len + additional),
+            None => Ok(()),
+        }
+    }
+
+    /// Trim the file to the length
+    pub fn try_shrink_to_fit(&mut self) -> io::Result<()> {
+        if self.cap == self.len {
+            return Ok(());
+        }
+        self.set_capacity(self.len)
+    }
+
+    pub fn extend_from_slice(&mut self, data: &[T]) -> io::Result<()> {
+        self.try_reserve(data.len())?;
+        let len = self.len;
+        self.len += data.len();
+        self.as_mut_slice()[len..].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Write back the mapping, the spare capacity is kept
+    pub fn flush(&mut self) -> io::Result<()> {
+        match &self.map {
+            Some(map) => map.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Like [`flush`](Self::flush), and wait until the contents reach the disk
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.flush()?;
+        self.file.sync_all()
+    }
+
+    /// Trim the spare capacity and [`sync`](Self::sync),
+    /// reports the errors that drop ignores
+    pub fn close(mut self) -> io::Result<()> {
+        self.try_shrink_to_fit()?;
+        self.sync()
+    }
+
+    pub(crate) fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.cap);
+        self.len = new_len;
+    }
+}
+
+impl<T: Pod> Drop for MmapVec<T> {
+    fn drop(&mut self) {
+        self.map = None;
+        let _ = self.file.set_len(file_len::<T>(self.len));
+    }
+}
+
+impl<T: Pod + fmt::Debug> fmt::Debug for MmapVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T: Pod> Deref for MmapVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+impl<T: Pod> DerefMut for MmapVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Pod> AsRef<[T]> for MmapVec<T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+impl<T: Pod> AsMut<[T]> for MmapVec<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T: Pod> Extend<T> for MmapVec<T> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.try_reserve(iter.size_hint().0).unwrap_or_else(|e| super::io_failed(e));
+        for elem in iter {
+            self.extend_from_slice(&[elem]).unwrap_or_else(|e| super::io_failed(e));
+        }
+    }
+}
+impl<'a, T: Pod> Extend<&'a T> for MmapVec<T> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+
+        let mut vec = unsafe { MmapVec::<u32>::open(&path) }.unwrap();
+        vec.extend(0..3000);
+        vec.set_len(10);
+        vec.flush().unwrap();
+        assert!(vec.capacity() >= 3000);
+        vec.try_shrink_to_fit().unwrap();
+        assert_eq!(vec.capacity(), 10);
+        vec[9] = 42;
+        drop(vec);
+
+        let vec = unsafe { MmapVec::<u32>::open(&path) }.unwrap();
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec[8..], [8, 42]);
+    }
+}
@@ -0,0 +1,343 @@
+use core::{mem::size_of_val, ops::RangeBounds};
+
+use crate::{
+    util::normalize_range, CapacityError, Drainable, Growable, SeqLike, SeqVecLike, SliceLikeMut,
+    Truncatable, VecLike, VecLikeSolid,
+};
+
+/// Snapshot of [`Instrumented`] counters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Metrics {
+    /// Capacity changes, e.g growth of `push`, `insert` and `reserve`
+    pub reallocs: usize,
+    /// Bytes shifted inside the buffer by `insert`, `remove`, `drain` and `retain`
+    pub moved_bytes: usize,
+    /// Buffer clones of shared pointers, e.g `Rc` and `Arc`
+    pub clones: usize,
+    /// Max length ever reached
+    pub peak_len: usize,
+}
+
+/// [`VecLike`] wrapper counting the costs of the operations, see [`Metrics`]
+///
+/// [`Offset`](crate::Offset) windows operate through the wrapper,
+/// so the costs of the window APIs are counted too
+///
+/// - `clones` is read from the global `hidden_clones`,
+///   only debug builds count it, and clones of other threads may be included
+/// - `retain` counts one unit of the slice per shifted element, exact for `[T]`
+/// - Mutations through [`SliceLikeMut`](crate::SliceLikeMut) or [`Growable::as_mut_collection`] are not counted
+///
+/// # Examples
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use offset_vec::{Instrumented, Offset};
+///
+/// let mut window = Instrumented::new(Vec::from_iter(0..8u32)).offset(4);
+/// window.retain(|&x| x != 5);
+///
+/// // only 6 and 7 are shifted, the prefix is walked but not moved
+/// let vec = window.origin_vec();
+/// assert_eq!(vec.metrics().moved_bytes, 2 * 4);
+/// assert_eq!(vec.inner(), &[0, 1, 2, 3, 4, 6, 7]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Instrumented<V> {
+    vec: V,
+    metrics: Metrics,
+}
+
+fn clones() -> usize {
+    #[cfg(feature = "alloc")]
+    return crate::hidden_clones();
+    #[cfg(not(feature = "alloc"))]
+    return 0;
+}
+
+impl<V: SeqLike> Instrumented<V> {
+    pub fn new(vec: V) -> Self {
+        let peak_len = vec.len();
+        Self { vec, metrics: Metrics { peak_len, ..Default::default() } }
+    }
+
+    #[inline]
+    pub fn metrics(&self) -> Metrics {
+        self.metrics
+    }
+
+    /// Reset counters, the peak length restarts from current length
+    pub fn reset_metrics(&mut self) {
+        self.metrics = Metrics { peak_len: self.vec.len(), ..Default::default() };
+    }
+
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.vec
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+}
+
+impl<V: VecLike> Instrumented<V> {
+    fn track<R>(&mut self, moved_bytes: usize, f: impl FnOnce(&mut V) -> R) -> R {
+        let capacity = self.vec.capacity();
+        let clones_before = clones();
+
+        let result = f(&mut self.vec);
+
+        let metrics = &mut self.metrics;
+        metrics.moved_bytes += moved_bytes;
+        metrics.clones += clones() - clones_before;
+        metrics.reallocs += usize::from(self.vec.capacity() != capacity);
+        metrics.peak_len = metrics.peak_len.max(self.vec.len());
+        result
+    }
+
+    /// Bytes of `[i..]`, or zero if out of bounds, let the operation panic
+    fn bytes_from(&self, i: usize) -> usize {
+        if i > self.vec.len() {
+            return 0;
+        }
+        size_of_val(&self.vec.as_slice()[i..])
+    }
+
+    /// Bytes of one unit of the slice, e.g `T` of `[T]` or `u8` of `str`
+    fn unit_bytes(&self) -> usize {
+        let len = self.vec.len();
+        size_of_val(self.vec.as_slice()).checked_div(len).unwrap_or(0)
+    }
+}
+
+crate::delegate_vec_like!(impl[V: VecLike] Instrumented<V> => vec: V; [
+    SeqLike, SliceLike, Offset, OffsetCheckRef
+]);
+crate::delegate_vec_like!(impl[V: VecLike + SliceLikeMut] Instrumented<V> => vec: V; [SliceLikeMut]);
+
+impl<V: VecLike> Truncatable for Instrumented<V> {
+    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.track(0, V::pop)
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        let elem = self.track(0, |vec| vec.remove(index));
+        self.metrics.moved_bytes += self.bytes_from(index);
+        elem
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.track(0, |vec| vec.truncate(len));
+    }
+
+    fn clear(&mut self) {
+        self.track(0, V::clear);
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(V::ElemRef<'_>) -> bool,
+    {
+        let unit_bytes = self.unit_bytes();
+        let (mut removed, mut moved) = (false, 0);
+        self.track(0, |vec| vec.retain(|elem| {
+            let keep = f(elem);
+            removed |= !keep;
+            moved += usize::from(keep && removed);
+            keep
+        }));
+        self.metrics.moved_bytes += moved * unit_bytes;
+    }
+}
+
+impl<V: VecLike> Growable for Instrumented<V> {
+    type Collection = V::Collection;
+
+    const MAX_CAPACITY: usize = V::MAX_CAPACITY;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self.vec.as_mut_collection()
+    }
+
+    fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.track(0, |vec| vec.push(value));
+    }
+
+    #[track_caller]
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.track(0, |vec| vec.try_push(value))
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        let moved = self.bytes_from(index);
+        let result = self.track(0, |vec| vec.try_insert(index, element));
+        if result.is_ok() {
+            self.metrics.moved_bytes += moved;
+        }
+        result
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        let moved = self.bytes_from(index);
+        self.track(moved, |vec| vec.insert(index, element));
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.track(0, |vec| vec.reserve(additional));
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.track(0, |vec| vec.reserve_exact(additional));
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.track(0, |vec| vec.shrink_to(min_capacity));
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.track(0, V::shrink_to_fit);
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.track(0, |vec| vec.append(other));
+    }
+}
+
+impl<V: VecLike> Drainable for Instrumented<V> {
+    type Drain<'a> = V::Drain<'a> where Self: 'a;
+
+    /// Count the shifting of the tail, as if the drain is fully consumed
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let range = normalize_range(range, self.vec.len());
+        let moved = if range.is_empty() { 0 } else { self.bytes_from(range.end) };
+
+        let Self { vec, metrics } = self;
+        let clones_before = clones();
+        let drain = vec.drain(range);
+        metrics.moved_bytes += moved;
+        metrics.clones += clones() - clones_before;
+        drain
+    }
+}
+
+impl<V: VecLike> SeqVecLike for Instrumented<V> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.track(0, |vec| vec.split_off(at))
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.track(0, |vec| vec.resize(new_len, value));
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        self.track(0, |vec| vec.resize_with(new_len, f));
+    }
+}
+
+impl<V: VecLike + VecLikeSolid> VecLikeSolid for Instrumented<V> {
+    /// Count the last element moved into `index`
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        let moved = if index+1 < self.vec.len() { self.bytes_from(self.vec.len()-1) } else { 0 };
+        self.track(moved, |vec| vec.swap_remove(index))
+    }
+
+    fn retain_mut<F>(&mut self, mut f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        let unit_bytes = self.unit_bytes();
+        let (mut removed, mut moved) = (false, 0);
+        self.track(0, |vec| vec.retain_mut(|elem| {
+            let keep = f(elem);
+            removed |= !keep;
+            moved += usize::from(keep && removed);
+            keep
+        }));
+        self.metrics.moved_bytes += moved * unit_bytes;
+    }
+
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        self.track(0, |vec| vec.pop_if(predicate))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{string::String, vec, vec::Vec};
+    use crate::Offset;
+    use super::*;
+
+    #[test]
+    fn window_shifts() {
+        let mut vec = Instrumented::new(vec![0u16; 8]);
+        let mut window = vec.offset_mut(6);
+        window.insert(1, 1);
+        window.remove(0);
+        let _ = window.drain(..1);
+        assert_eq!(vec.metrics().moved_bytes, 2 + 4 + 2);
+        assert_eq!(vec.metrics().peak_len, 9);
+    }
+
+    #[test]
+    fn string_units() {
+        let mut s = Instrumented::new(String::from("ab"));
+        s.reset_metrics();
+        s.insert(0, '你');
+        s.remove(0);
+        assert_eq!(s.metrics().moved_bytes, 2 + 2);
+        assert_eq!(s.metrics().peak_len, 5);
+    }
+
+    #[test]
+    fn reallocs() {
+        let mut vec = Instrumented::new(Vec::new());
+        vec.reserve(4);
+        for i in 0..4 {
+            vec.push(i);
+        }
+        vec.push(4);
+        assert_eq!(vec.metrics().reallocs, 2);
+        assert_eq!(vec.metrics().moved_bytes, 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn shared_clones() {
+        use alloc::rc::Rc;
+
+        let mut rc = Instrumented::new(Rc::new(vec![1, 2]));
+        let shared = rc.inner().clone();
+        rc.offset_mut(1).push(3);
+        rc.push(4);
+        assert!(rc.metrics().clones >= 1);
+        assert_eq!(*shared, [1, 2]);
+    }
+}
@@ -1,8 +1,12 @@
-#![doc = include_str!("../README.md")]
+#![cfg_attr(feature = "alloc", doc = include_str!("../README.md"))]
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
+#[cfg(any(feature = "alloc", test, doc))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(doc)]
 use alloc::{vec::Vec, string::String};
@@ -14,13 +18,60 @@ use core::{
 };
 
 mod util;
+mod delegate;
 mod slice;
 mod offset;
 mod vec_like;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod utf16;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod sentinel;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod aligned;
+mod uninit;
+mod array_vec;
+mod slice_vec;
+mod instrumented;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod shared_window;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod os_str;
+#[cfg(feature = "file-vec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-vec")))]
+mod file_vec;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod dyn_vec_like;
+#[cfg(any(feature = "testing", all(test, feature = "alloc")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 
 pub use slice::*;
 pub use offset::*;
 pub use vec_like::*;
+#[cfg(feature = "alloc")]
+pub use utf16::*;
+#[cfg(feature = "alloc")]
+pub use sentinel::*;
+#[cfg(feature = "alloc")]
+pub use aligned::*;
+pub use array_vec::ArrayVecLike;
+pub use slice_vec::SliceVec;
+pub use uninit::UninitDrain;
+pub use instrumented::{Instrumented, Metrics};
+#[cfg(feature = "alloc")]
+pub use shared_window::{SharedBuf, SharedWindow};
+#[cfg(feature = "alloc")]
+pub use dyn_vec_like::DynVecLike;
+#[cfg(feature = "std")]
+pub use os_str::OsSlice;
+#[cfg(feature = "file-vec")]
+pub use file_vec::*;
 
 mod externs {
     mod core_impls;
@@ -34,7 +85,8 @@ mod externs {
 ///
 /// # Examples
 ///
-/// ```
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
 /// use offset_vec::Offset;
 ///
 /// let mut vec = vec![0, 1, 2, 3, 4];
@@ -47,12 +99,12 @@ mod externs {
 /// assert_eq!(vec, [0, 1, 2, 5, 4]);
 /// ```
 #[derive(Debug, Clone, Default)]
-pub struct OffsetVec<V: VecLike> {
+pub struct OffsetVec<V: SeqLike> {
     vec: V,
     offset: usize,
 }
 
-impl<V: VecLike> Deref for OffsetVec<V> {
+impl<V: SliceLike> Deref for OffsetVec<V> {
     type Target = V::Slice;
 
     #[inline]
@@ -63,7 +115,7 @@ impl<V: VecLike> Deref for OffsetVec<V> {
     }
 }
 
-impl<V: VecLike> DerefMut for OffsetVec<V> {
+impl<V: SliceLikeMut> DerefMut for OffsetVec<V> where V::Slice: SliceMut {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         let offset = self.offset;
@@ -73,7 +125,7 @@ impl<V: VecLike> DerefMut for OffsetVec<V> {
 }
 
 impl<V, I> Index<I> for OffsetVec<V>
-where V: VecLike,
+where V: SliceLike,
       I: SliceIndex<V::Slice>,
       V::Slice: Index<I>,
 {
@@ -87,9 +139,9 @@ where V: VecLike,
 }
 
 impl<V, I> IndexMut<I> for OffsetVec<V>
-where V: VecLike,
+where V: SliceLikeMut,
       I: SliceIndex<V::Slice>,
-      V::Slice: IndexMut<I>,
+      V::Slice: IndexMut<I> + SliceMut,
 {
     #[track_caller]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
@@ -98,12 +150,13 @@ where V: VecLike,
     }
 }
 
-impl<V: VecLike> OffsetVec<V> {
+impl<V: SeqLike> OffsetVec<V> {
     /// Get original vector
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = vec![0, 1, 2, 3, 4];
@@ -121,7 +174,8 @@ impl<V: VecLike> OffsetVec<V> {
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = vec![0, 1, 2, 3, 4];
@@ -142,7 +196,8 @@ impl<V: VecLike> OffsetVec<V> {
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let vec = vec![0, 1, 2, 3, 4];
@@ -156,43 +211,71 @@ impl<V: VecLike> OffsetVec<V> {
         self.vec
     }
 
-    /// Extracts a slice containing the offset vector.
-    ///
-    /// Equivalent to &s[..].
+    /// `self.len() == 0`
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get offset vector length
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let vec = vec![0, 1, 2, 3, 4];
     /// let mut vec1 = vec.offset(2);
     ///
-    /// assert_eq!(vec1.as_slice(), &[2, 3, 4]);
-    /// assert_eq!(&vec1[..], &[2, 3, 4]);
+    /// assert_eq!(vec1.len(), 3);
+    /// assert_eq!(vec1.origin_vec().len(), 5);
     /// ```
     #[inline]
-    pub fn as_slice(&self) -> &V::Slice {
-        self
+    pub fn len(&self) -> usize {
+        self.vec.len() - self.offset
     }
 
-    /// Extracts a mutable slice containing the offset vector.
-    ///
-    /// Equivalent to &mut s[..].
+    /// Get offset
     ///
     /// # Examples
     ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// use offset_vec::Offset;
+    ///
+    /// let vec = vec![0, 1, 2, 3, 4];
+    /// let vec1 = vec.offset(2);
+    ///
+    /// assert_eq!(vec1.origin_offset(), 2);
+    ///
+    /// let vec2 = vec1.offset(1);
+    /// assert_eq!(vec2.origin_offset(), 3);
     /// ```
+    pub fn origin_offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<V: SliceLike> OffsetVec<V> {
+    /// Extracts a slice containing the offset vector.
+    ///
+    /// Equivalent to &s[..].
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let vec = vec![0, 1, 2, 3, 4];
     /// let mut vec1 = vec.offset(2);
     ///
-    /// assert_eq!(vec1.as_mut_slice(), &mut [2, 3, 4]);
-    /// assert_eq!(&mut vec1[..], &mut [2, 3, 4]);
+    /// assert_eq!(vec1.as_slice(), &[2, 3, 4]);
+    /// assert_eq!(&vec1[..], &[2, 3, 4]);
     /// ```
     #[inline]
-    pub fn as_mut_slice(&mut self) -> &mut V::Slice {
+    pub fn as_slice(&self) -> &V::Slice {
         self
     }
 
@@ -200,7 +283,8 @@ impl<V: VecLike> OffsetVec<V> {
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let vec = vec![0, 1, 2, 3, 4];
@@ -214,12 +298,38 @@ impl<V: VecLike> OffsetVec<V> {
     {
         self.as_slice().into_iter()
     }
+}
 
-    /// Mutable iterator
+impl<V: SliceLikeMut> OffsetVec<V> {
+    /// Extracts a mutable slice containing the offset vector.
+    ///
+    /// Equivalent to &mut s[..].
     ///
     /// # Examples
     ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// use offset_vec::Offset;
+    ///
+    /// let vec = vec![0, 1, 2, 3, 4];
+    /// let mut vec1 = vec.offset(2);
+    ///
+    /// assert_eq!(vec1.as_mut_slice(), &mut [2, 3, 4]);
+    /// assert_eq!(&mut vec1[..], &mut [2, 3, 4]);
     /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut V::Slice
+    where V::Slice: SliceMut,
+    {
+        self
+    }
+
+    /// Mutable iterator
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = vec![0, 1, 2, 3, 4];
@@ -233,40 +343,135 @@ impl<V: VecLike> OffsetVec<V> {
     /// assert_eq!(vec, [0, 1, 2, 5, 4]);
     /// ```
     pub fn iter_mut<'a>(&'a mut self) -> <&'a mut V::Slice as IntoIterator>::IntoIter
-    where &'a mut V::Slice: IntoIterator
+    where &'a mut V::Slice: IntoIterator,
+          V::Slice: SliceMut,
     {
         self.as_mut_slice().into_iter()
     }
+}
 
-    /// `self.len() == 0`
-    #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+impl<V: Truncatable> OffsetVec<V> {
+    /// Pop a value
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// use offset_vec::Offset;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut vec1 = vec.offset_mut(2);
+    ///
+    /// assert_eq!(vec1, [2, 3]);
+    /// assert_eq!(vec1.pop(), Some(3));
+    /// assert_eq!(vec1, [2]);
+    /// assert_eq!(vec1.pop(), Some(2));
+    /// assert_eq!(vec1, []);
+    /// assert_eq!(vec1.pop(), None);
+    /// assert_eq!(vec1, []);
+    ///
+    /// assert_eq!(vec1.origin_vec(), &&mut [0, 1]);
+    /// assert_eq!(vec, [0, 1]);
+    /// ```
+    pub fn pop(&mut self) -> Option<V::Elem> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.vec.pop()
     }
 
-    /// Get offset vector length
+    /// Remove a value at index, shifting all elements after it to the left.
     ///
     /// # Examples
     ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// use offset_vec::Offset;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut vec1 = vec.offset_mut(2);
+    ///
+    /// assert_eq!(vec1, [2, 3, 4, 5]);
+    /// assert_eq!(vec1.remove(1), 3);
+    /// assert_eq!(vec1, [2, 4, 5]);
+    ///
+    /// assert_eq!(vec, [0, 1, 2, 4, 5]);
     /// ```
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> V::Elem {
+        let len = self.len();
+        if index >= len {
+            index_out_of_range(index, self.offset, len)
+        }
+        self.vec.remove(index + self.offset)
+    }
+
+    /// Truncate to length
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
-    /// let vec = vec![0, 1, 2, 3, 4];
-    /// let mut vec1 = vec.offset(2);
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut vec1 = vec.offset_mut(2);
     ///
-    /// assert_eq!(vec1.len(), 3);
-    /// assert_eq!(vec1.origin_vec().len(), 5);
+    /// assert_eq!(vec1, [2, 3, 4, 5]);
+    ///
+    /// vec1.truncate(2);
+    ///
+    /// assert_eq!(vec1, [2, 3]);
+    /// assert_eq!(vec, [0, 1, 2, 3]);
     /// ```
-    #[inline]
-    pub fn len(&self) -> usize {
-        self.vec.len() - self.offset
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len + self.offset);
     }
 
-    /// Get offset vector capacity
+    /// Clear all elements (offset)
     ///
     /// # Examples
     ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// use offset_vec::Offset;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut vec1 = vec.offset_mut(2);
+    ///
+    /// assert_eq!(vec1, [2, 3, 4]);
+    ///
+    /// vec1.clear();
+    ///
+    /// assert_eq!(vec1, []);
+    /// assert_eq!(vec, [0, 1]);
     /// ```
+    pub fn clear(&mut self) {
+        self.vec.truncate(self.offset);
+    }
+}
+
+impl<V: Truncatable + SliceLike> OffsetVec<V> {
+    pub fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(V::ElemRef<'_>) -> bool,
+    {
+        let i = self.vec.as_slice().transform_index(self.offset);
+
+        let mut i = 0..i;
+        self.vec.retain(|elem| {
+            i.next().is_some() || f(elem)
+        });
+    }
+}
+
+impl<V: Growable> OffsetVec<V> {
+    /// Get offset vector capacity
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = Vec::with_capacity(5);
@@ -293,34 +498,17 @@ impl<V: VecLike> OffsetVec<V> {
         self.vec.shrink_to_fit();
     }
 
+    /// `min_capacity` is of the window, like [`capacity`](Self::capacity)
     pub fn shrink_to(&mut self, min_capacity: usize) {
-        self.vec.shrink_to(min_capacity);
-    }
-
-    /// Get offset
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use offset_vec::Offset;
-    ///
-    /// let vec = vec![0, 1, 2, 3, 4];
-    /// let vec1 = vec.offset(2);
-    ///
-    /// assert_eq!(vec1.origin_offset(), 2);
-    ///
-    /// let vec2 = vec1.offset(1);
-    /// assert_eq!(vec2.origin_offset(), 3);
-    /// ```
-    pub fn origin_offset(&self) -> usize {
-        self.offset
+        self.vec.shrink_to(min_capacity.saturating_add(self.offset));
     }
 
     /// Push a value
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = vec![0, 1, 2, 3, 4];
@@ -335,65 +523,32 @@ impl<V: VecLike> OffsetVec<V> {
         self.vec.push(value);
     }
 
-    /// Pop a value
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use offset_vec::Offset;
+    /// Push a value, return the error when capacity is full
     ///
-    /// let mut vec = vec![0, 1, 2, 3];
-    /// let mut vec1 = vec.offset_mut(2);
-    ///
-    /// assert_eq!(vec1, [2, 3]);
-    /// assert_eq!(vec1.pop(), Some(3));
-    /// assert_eq!(vec1, [2]);
-    /// assert_eq!(vec1.pop(), Some(2));
-    /// assert_eq!(vec1, []);
-    /// assert_eq!(vec1.pop(), None);
-    /// assert_eq!(vec1, []);
-    ///
-    /// assert_eq!(vec1.origin_vec(), &&mut [0, 1]);
-    /// assert_eq!(vec, [0, 1]);
-    /// ```
-    pub fn pop(&mut self) -> Option<V::Elem> {
-        if self.is_empty() {
-            return None;
-        }
-
-        self.vec.pop()
-    }
-
-    /// Remove a value at index, shifting all elements after it to the left.
+    /// Only fixed-capacity backends may fail, e.g [`ArrayVecLike`]
     ///
     /// # Examples
     ///
     /// ```
-    /// use offset_vec::Offset;
+    /// use offset_vec::{ArrayVecLike, Offset};
     ///
-    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
-    /// let mut vec1 = vec.offset_mut(2);
-    ///
-    /// assert_eq!(vec1, [2, 3, 4, 5]);
-    /// assert_eq!(vec1.remove(1), 3);
-    /// assert_eq!(vec1, [2, 4, 5]);
+    /// let mut vec = ArrayVecLike::<i32, 3>::from_iter([0, 1]);
+    /// let mut vec1 = vec.offset_mut(1);
     ///
-    /// assert_eq!(vec, [0, 1, 2, 4, 5]);
+    /// assert_eq!(vec1.try_push(2), Ok(()));
+    /// assert_eq!(vec1.try_push(3).unwrap_err().into_element(), 3);
+    /// assert_eq!(vec1, [1, 2]);
     /// ```
-    #[track_caller]
-    pub fn remove(&mut self, index: usize) -> V::Elem {
-        let len = self.len();
-        if index > len {
-            index_out_of_range(index, self.offset, len)
-        }
-        self.vec.remove(index + self.offset)
+    pub fn try_push(&mut self, value: V::Elem) -> Result<(), CapacityError<V::Elem>> {
+        self.vec.try_push(value)
     }
 
     /// Insert a value before index, shifting all elements after it to the right.
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = vec![0, 1, 2, 4, 5];
@@ -416,32 +571,37 @@ impl<V: VecLike> OffsetVec<V> {
         self.vec.insert(index + self.offset, elem)
     }
 
-    /// Truncate to length
+    /// Insert a value before index, return the error when capacity is full
+    ///
+    /// Only fixed-capacity backends may fail, e.g [`ArrayVecLike`]
     ///
     /// # Examples
     ///
     /// ```
-    /// use offset_vec::Offset;
-    ///
-    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
-    /// let mut vec1 = vec.offset_mut(2);
-    ///
-    /// assert_eq!(vec1, [2, 3, 4, 5]);
+    /// use offset_vec::{ArrayVecLike, Offset};
     ///
-    /// vec1.truncate(2);
+    /// let mut vec = ArrayVecLike::<i32, 3>::from_iter([0, 2]);
+    /// let mut vec1 = vec.offset_mut(1);
     ///
-    /// assert_eq!(vec1, [2, 3]);
-    /// assert_eq!(vec, [0, 1, 2, 3]);
+    /// assert_eq!(vec1.try_insert(0, 1), Ok(()));
+    /// assert_eq!(vec1.try_insert(0, 3).unwrap_err().into_element(), 3);
+    /// assert_eq!(vec, [0, 1, 2]);
     /// ```
-    pub fn truncate(&mut self, len: usize) {
-        self.vec.truncate(len + self.offset);
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, elem: V::Elem) -> Result<(), CapacityError<V::Elem>> {
+        let len = self.len();
+        if index > len {
+            index_out_of_range(index, self.offset, len)
+        }
+        self.vec.try_insert(index + self.offset, elem)
     }
 
     /// Append and clear other collection
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = vec![0, 1, 2, 3];
@@ -458,28 +618,9 @@ impl<V: VecLike> OffsetVec<V> {
     pub fn append(&mut self, other: &mut V::Collection) {
         self.vec.append(other);
     }
+}
 
-    /// Clear all elements (offset)
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use offset_vec::Offset;
-    ///
-    /// let mut vec = vec![0, 1, 2, 3, 4];
-    /// let mut vec1 = vec.offset_mut(2);
-    ///
-    /// assert_eq!(vec1, [2, 3, 4]);
-    ///
-    /// vec1.clear();
-    ///
-    /// assert_eq!(vec1, []);
-    /// assert_eq!(vec, [0, 1]);
-    /// ```
-    pub fn clear(&mut self) {
-        self.vec.truncate(self.offset);
-    }
-
+impl<V: Drainable> OffsetVec<V> {
     #[track_caller]
     fn map_range<R: RangeBounds<usize>>(&self, range: R) -> Range<usize> {
         let start = match range.start_bound() {
@@ -518,7 +659,8 @@ impl<V: VecLike> OffsetVec<V> {
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
@@ -536,12 +678,15 @@ impl<V: VecLike> OffsetVec<V> {
     pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> V::Drain<'_> {
         self.vec.drain(self.map_range(range))
     }
+}
 
+impl<V: SeqVecLike> OffsetVec<V> {
     /// Splits the collection into two at the given index.
     ///
     /// # Examples
     ///
-    /// ```
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
     /// use offset_vec::Offset;
     ///
     /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
@@ -576,17 +721,6 @@ impl<V: VecLike> OffsetVec<V> {
     {
         self.vec.resize_with(new_len+self.offset, f);
     }
-
-    pub fn retain<F>(&mut self, mut f: F)
-    where F: FnMut(V::ElemRef<'_>) -> bool,
-    {
-        let i = self.vec.as_slice().transform_index(self.offset);
-
-        let mut i = 0..i;
-        self.vec.retain(|elem| {
-            i.next().is_some() || f(elem)
-        });
-    }
 }
 
 impl<V: VecLikeSolid> OffsetVec<V> {
@@ -600,7 +734,7 @@ impl<V: VecLikeSolid> OffsetVec<V> {
     #[track_caller]
     pub fn swap_remove(&mut self, index: usize) -> V::Elem {
         let len = self.len();
-        if index > len {
+        if index >= len {
             index_out_of_range(index, self.offset, len)
         }
         self.vec.swap_remove(index + self.offset)
@@ -617,17 +751,74 @@ impl<V: VecLikeSolid> OffsetVec<V> {
     }
 }
 
-impl<V: VecLike<Slice = str>> OffsetVec<V> {
+impl<V: SeqLike + SharedMut> OffsetVec<V>
+where V::Target: SeqLike,
+{
+    /// The next mutation through the window would clone the whole origin buffer
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// use std::rc::Rc;
+    /// use offset_vec::Offset;
+    ///
+    /// let vec = Rc::new(vec![0, 1, 2, 3]);
+    /// let window = vec.clone().offset(2);
+    /// assert!(window.would_clone());
+    ///
+    /// drop(vec);
+    /// assert!(!window.would_clone());
+    /// ```
+    #[inline]
+    pub fn would_clone(&self) -> bool {
+        self.vec.would_clone()
+    }
+
+    /// Borrow the window without cloning the buffer,
+    /// the mutations through it never clone
+    ///
+    /// # Errors
+    /// Return [`SharedError`] if [`would_clone`](Self::would_clone),
+    /// or a weak pointer of [`Arc`](alloc::sync::Arc) lives
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "alloc", doc = "```")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// use std::rc::Rc;
+    /// use offset_vec::{Offset, SharedError};
+    ///
+    /// let vec = Rc::new(vec![0, 1, 2, 3]);
+    /// let mut window = vec.clone().offset(2);
+    /// assert_eq!(window.try_make_mut().unwrap_err(), SharedError);
+    ///
+    /// drop(vec);
+    /// window.try_make_mut()?.push(4);
+    /// assert_eq!(window, [2, 3, 4]);
+    /// # Ok::<_, SharedError>(())
+    /// ```
+    pub fn try_make_mut(&mut self) -> Result<OffsetVec<&mut V::Target>, SharedError> {
+        let offset = self.offset;
+        Ok(OffsetVec { vec: self.vec.try_make_mut()?, offset })
+    }
+}
+
+impl<V: SliceLike<Slice = str>> OffsetVec<V> {
     #[inline]
     pub fn as_str(&self) -> &str {
         self
     }
+}
 
+impl<V: SliceLikeMut<Slice = str>> OffsetVec<V> {
     #[inline]
     pub fn as_mut_str(&mut self) -> &mut str {
         self
     }
+}
 
+impl<V: Growable + SliceLike<Slice = str>> OffsetVec<V> {
     pub fn push_str<'a>(&mut self, s: &'a str)
     where V::Collection: Extend<&'a str>,
     {
@@ -635,6 +826,148 @@ impl<V: VecLike<Slice = str>> OffsetVec<V> {
     }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: SliceLike<Slice = Utf16Str>> OffsetVec<V> {
+    #[inline]
+    pub fn as_utf16_str(&self) -> &Utf16Str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: SliceLikeMut<Slice = Utf16Str>> OffsetVec<V> {
+    #[inline]
+    pub fn as_mut_utf16_str(&mut self) -> &mut Utf16Str {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<V: SliceLike<Slice = OsSlice>> OffsetVec<V> {
+    #[inline]
+    pub fn as_os_str(&self) -> &std::ffi::OsStr {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<V: Growable + SliceLike<Slice = OsSlice>> OffsetVec<V> {
+    /// Push a [`OsStr`](std::ffi::OsStr)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use offset_vec::Offset;
+    /// use std::ffi::OsString;
+    ///
+    /// let mut cmd = OsString::from("echo");
+    /// let mut arg = cmd.offset_mut(4);
+    ///
+    /// arg.push_os_str(" foo".as_ref());
+    /// assert_eq!(arg.as_os_str(), " foo");
+    /// assert_eq!(arg.to_string(), " foo");
+    /// assert_eq!(cmd, "echo foo");
+    /// ```
+    pub fn push_os_str<'a>(&mut self, s: &'a std::ffi::OsStr)
+    where V::Collection: Extend<&'a std::ffi::OsStr>,
+    {
+        self.vec.as_mut_collection().extend(once(s));
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl<V: SeqVecLike<Collection = bytes::BytesMut>> OffsetVec<V> {
+    /// Split the window off the origin buffer without copying, the window becomes empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::BytesMut;
+    /// use offset_vec::Offset;
+    ///
+    /// let mut buf = BytesMut::from("HEADbody");
+    /// let mut body = buf.offset_mut(4);
+    ///
+    /// let payload = body.split_window();
+    /// assert_eq!(payload, "body");
+    /// assert!(body.is_empty());
+    /// assert_eq!(buf, "HEAD");
+    /// ```
+    #[must_use = "use `.clear()` if you don't need the window"]
+    pub fn split_window(&mut self) -> bytes::BytesMut {
+        self.vec.split_off(self.offset)
+    }
+
+    /// Like [`split_window`](Self::split_window), and freeze into [`Bytes`](bytes::Bytes)
+    #[must_use = "use `.clear()` if you don't need the window"]
+    pub fn freeze_window(&mut self) -> bytes::Bytes {
+        self.split_window().freeze()
+    }
+}
+
+#[cfg(feature = "imbl")]
+macro_rules! impl_imbl {
+    ($($r:tt)*) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "imbl")))]
+        impl<T: Clone, P: imbl::shared_ptr::SharedPointerKind> OffsetVec<$($r)* imbl::GenericVector<T, P>> {
+            /// Immutable iterator over the chunked window,
+            /// [`iter`](Self::iter) is only available on contiguous windows
+            ///
+            /// [`Clone`] of the window is O(1), the chunks are shared
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use imbl::Vector;
+            /// use offset_vec::Offset;
+            ///
+            /// let vec1 = (0..100).collect::<Vector<_>>().offset(97);
+            /// let vec2 = vec1.clone();
+            /// assert!(vec1.origin_vec().ptr_eq(vec2.origin_vec()));
+            ///
+            /// let x: Vec<_> = vec2.iter_window().collect();
+            /// assert_eq!(x, [&97, &98, &99]);
+            /// ```
+            pub fn iter_window(&self) -> imbl::vector::Iter<'_, T, P> {
+                self.vec.focus().narrow(self.offset..).into_iter()
+            }
+
+            /// Returns a reference to an element in the window
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use imbl::vector;
+            /// use offset_vec::Offset;
+            ///
+            /// let vec1 = vector![0, 1, 2, 3, 4].offset(2);
+            ///
+            /// assert_eq!(vec1.get(1), Some(&3));
+            /// assert_eq!(vec1.get(3), None);
+            /// ```
+            pub fn get(&self, index: usize) -> Option<&T> {
+                self.vec.get(self.offset.checked_add(index)?)
+            }
+
+            /// Returns a mutable reference to an element in the window,
+            /// copying the chunk path first when it's shared
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+                let index = self.offset.checked_add(index)?;
+                self.vec.get_mut(index)
+            }
+        }
+    };
+}
+#[cfg(feature = "imbl")]
+impl_imbl!();
+#[cfg(feature = "imbl")]
+impl_imbl!(&mut);
+
 #[cold]
 #[track_caller]
 #[inline(never)]
@@ -1,26 +1,41 @@
+#[cfg(feature = "alloc")]
 use alloc::{boxed::Box, rc::Rc, string::String, sync::Arc, vec::Vec};
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
+#[cfg(feature = "std")]
+use std::sync::{MutexGuard, RwLockWriteGuard};
+use core::cell::RefMut;
 
 mod check;
 
 pub use check::*;
 
-use crate::{VecLike, OffsetVec};
+use crate::{ArrayVecLike, SeqLike, SharedError, SliceVec, OffsetVec};
+#[cfg(feature = "alloc")]
+use crate::{arc_make_mut, rc_make_mut, AlignedVec, DynVecLike, SentinelVec, SharedMut, Utf16String};
 
 #[track_caller]
-pub fn create<V: VecLike>(vec: V, offset: usize) -> OffsetVec<V> {
+pub fn create<V: SeqLike>(vec: V, offset: usize) -> OffsetVec<V> {
     OffsetVec { vec, offset }.offset_check()
 }
 
 pub trait Offset {
-    type Output: VecLike;
-    type OutputMut: VecLike;
+    type Output: SeqLike;
+    type OutputMut: SeqLike;
 
     fn offset(self, i: usize) -> OffsetVec<Self::Output>;
 
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut>;
+
+    /// Like [`offset_mut`](Offset::offset_mut), but fails instead of cloning a shared buffer,
+    /// e.g [`Rc`](alloc::rc::Rc) and [`Arc`](alloc::sync::Arc)
+    #[track_caller]
+    fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+        Ok(self.offset_mut(i))
+    }
 }
 
-impl<'a, V: Offset + VecLike> Offset for &'a mut V {
+impl<'a, V: Offset + SeqLike> Offset for &'a mut V {
     type Output = &'a mut V::OutputMut;
     type OutputMut = V::OutputMut;
 
@@ -33,9 +48,101 @@ impl<'a, V: Offset + VecLike> Offset for &'a mut V {
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
         (**self).offset_mut(i).offset_check()
     }
+
+    #[track_caller]
+    fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+        Ok((**self).try_offset_mut(i)?.offset_check())
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_alloc {
+    ($($A:ident)?) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: Offset<Output = V> + SeqLike $(, $A: Allocator)?> Offset for Box<V $(, $A)?> {
+            type Output = Self;
+            type OutputMut = V::OutputMut;
+
+            #[track_caller]
+            fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+                create(self, i)
+            }
+
+            #[track_caller]
+            fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
+                (**self).offset_mut(i).offset_check()
+            }
+
+            #[track_caller]
+            fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+                Ok((**self).try_offset_mut(i)?.offset_check())
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<T $(, $A: Allocator)?> Offset for Vec<T $(, $A)?> {
+            type Output = Self;
+            type OutputMut = Self;
+
+            #[track_caller]
+            fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+                create(self, i)
+            }
+
+            #[track_caller]
+            fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+                create(self, i)
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl_alloc!();
+#[cfg(feature = "allocator_api")]
+impl_alloc!(A);
+
+macro_rules! impl_guard {
+    ($(#[$attr:meta])* $G:ident) => {
+        /// The window holds the guard, so the borrow or lock is released on drop
+        $(#[$attr])*
+        impl<V: Offset<Output = V> + SeqLike> Offset for $G<'_, V> {
+            type Output = Self;
+            type OutputMut = V::OutputMut;
+
+            #[track_caller]
+            fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+                create(self, i)
+            }
+
+            #[track_caller]
+            fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
+                (**self).offset_mut(i).offset_check()
+            }
+
+            #[track_caller]
+            fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+                Ok((**self).try_offset_mut(i)?.offset_check())
+            }
+        }
+    };
 }
 
-impl<V: Offset<Output = V> + VecLike> Offset for Box<V> {
+impl_guard!(RefMut);
+impl_guard!(
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    MutexGuard
+);
+impl_guard!(
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    RwLockWriteGuard
+);
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Offset<Output = V> + SeqLike + Clone> Offset for Rc<V> {
     type Output = Self;
     type OutputMut = V::OutputMut;
 
@@ -44,13 +151,21 @@ impl<V: Offset<Output = V> + VecLike> Offset for Box<V> {
         create(self, i)
     }
 
+    /// Clone the buffer if shared, see [`hidden_clones`](crate::hidden_clones)
     #[track_caller]
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
-        (**self).offset_mut(i).offset_check()
+        rc_make_mut(self).offset_mut(i).offset_check()
+    }
+
+    #[track_caller]
+    fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+        Ok(self.try_make_mut()?.try_offset_mut(i)?.offset_check())
     }
 }
 
-impl<V: Offset<Output = V> + VecLike + Clone> Offset for Rc<V> {
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Offset<Output = V> + SeqLike + Clone> Offset for Arc<V> {
     type Output = Self;
     type OutputMut = V::OutputMut;
 
@@ -59,15 +174,23 @@ impl<V: Offset<Output = V> + VecLike + Clone> Offset for Rc<V> {
         create(self, i)
     }
 
+    /// Clone the buffer if shared, see [`hidden_clones`](crate::hidden_clones)
     #[track_caller]
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
-        Self::make_mut(self).offset_mut(i).offset_check()
+        arc_make_mut(self).offset_mut(i).offset_check()
+    }
+
+    #[track_caller]
+    fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+        Ok(self.try_make_mut()?.try_offset_mut(i)?.offset_check())
     }
 }
 
-impl<V: Offset<Output = V> + VecLike + Clone> Offset for Arc<V> {
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T, C> Offset for &mut (dyn DynVecLike<T, C> + '_) {
     type Output = Self;
-    type OutputMut = V::OutputMut;
+    type OutputMut = Self;
 
     #[track_caller]
     fn offset(self, i: usize) -> OffsetVec<Self::Output> {
@@ -76,12 +199,11 @@ impl<V: Offset<Output = V> + VecLike + Clone> Offset for Arc<V> {
 
     #[track_caller]
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
-        Self::make_mut(self).offset_mut(i).offset_check()
+        create(self, i)
     }
 }
 
-
-impl<V: Offset + VecLike> Offset for OffsetVec<V> {
+impl<V: Offset + SeqLike> Offset for OffsetVec<V> {
     type Output = V::Output;
     type OutputMut = V::OutputMut;
 
@@ -94,26 +216,101 @@ impl<V: Offset + VecLike> Offset for OffsetVec<V> {
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
         self.vec.offset_mut(self.offset+i).offset_check()
     }
+
+    #[track_caller]
+    fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+        Ok(self.vec.try_offset_mut(self.offset+i)?.offset_check())
+    }
 }
 
-impl<T> Offset for Vec<T> {
-    type Output = Vec<T>;
-    type OutputMut = Vec<T>;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Offset for String {
+    type Output = String;
+    type OutputMut = String;
 
     #[track_caller]
     fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        let _ = &self[i..];
         create(self, i)
     }
 
     #[track_caller]
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        let _ = &self[i..];
         create(self, i)
     }
 }
 
-impl Offset for String {
-    type Output = String;
-    type OutputMut = String;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T: Clone> Offset for SentinelVec<T> {
+    type Output = SentinelVec<T>;
+    type OutputMut = SentinelVec<T>;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<const ALIGN: usize> Offset for AlignedVec<ALIGN> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+impl<T, const N: usize> Offset for ArrayVecLike<T, N> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+impl<'a, T> Offset for SliceVec<'a, T> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Offset for Utf16String {
+    type Output = Utf16String;
+    type OutputMut = Utf16String;
 
     #[track_caller]
     fn offset(self, i: usize) -> OffsetVec<Self::Output> {
@@ -128,6 +325,25 @@ impl Offset for String {
     }
 }
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Offset for std::ffi::OsString {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        let _ = &crate::OsSlice::new(&self)[i..];
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        let _ = &crate::OsSlice::new(self)[i..];
+        create(self, i)
+    }
+}
+
 #[cfg(feature = "smallvec")]
 #[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
 impl<A: smallvec::Array> Offset for smallvec::SmallVec<A> {
@@ -200,7 +416,7 @@ impl<T> Offset for rc_vec::ArcVec<T> {
 
 #[cfg(feature = "unique-rc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "unique-rc")))]
-impl<V: Offset<Output = V> + VecLike> Offset for unique_rc::UniqRc<V> {
+impl<V: Offset<Output = V> + SeqLike> Offset for unique_rc::UniqRc<V> {
     type Output = Self;
     type OutputMut = V::OutputMut;
 
@@ -213,11 +429,16 @@ impl<V: Offset<Output = V> + VecLike> Offset for unique_rc::UniqRc<V> {
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
         (**self).offset_mut(i).offset_check()
     }
+
+    #[track_caller]
+    fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+        Ok((**self).try_offset_mut(i)?.offset_check())
+    }
 }
 
 #[cfg(feature = "unique-rc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "unique-rc")))]
-impl<V: Offset<Output = V> + VecLike> Offset for unique_rc::UniqArc<V> {
+impl<V: Offset<Output = V> + SeqLike> Offset for unique_rc::UniqArc<V> {
     type Output = Self;
     type OutputMut = V::OutputMut;
 
@@ -230,9 +451,265 @@ impl<V: Offset<Output = V> + VecLike> Offset for unique_rc::UniqArc<V> {
     fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::OutputMut> {
         (**self).offset_mut(i).offset_check()
     }
+
+    #[track_caller]
+    fn try_offset_mut(&mut self, i: usize) -> Result<OffsetVec<&mut Self::OutputMut>, SharedError> {
+        Ok((**self).try_offset_mut(i)?.offset_check())
+    }
+}
+
+#[cfg(feature = "bumpalo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bumpalo")))]
+impl<'bump, T> Offset for bumpalo::collections::Vec<'bump, T> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "bumpalo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bumpalo")))]
+impl<'bump> Offset for bumpalo::collections::String<'bump> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        let _ = &self[i..];
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        let _ = &self[i..];
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl Offset for bytes::BytesMut {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "compact_str")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compact_str")))]
+impl Offset for compact_str::CompactString {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        let _ = &self[i..];
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        let _ = &self[i..];
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "smartstring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smartstring")))]
+impl<Mode: smartstring::SmartStringMode> Offset for smartstring::SmartString<Mode> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        let _ = &self[i..];
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        let _ = &self[i..];
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "thin-vec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "thin-vec")))]
+impl<T> Offset for thin_vec::ThinVec<T> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+/// Unlike [`Rc<V>`], the buffer is not cloned on [`offset_mut`](Offset::offset_mut),
+/// only on the first mutation through the window while it is shared
+#[cfg(feature = "ecow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecow")))]
+impl<T: Clone> Offset for ecow::EcoVec<T> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+/// Unlike [`Rc<V>`], the buffer is not cloned on [`offset_mut`](Offset::offset_mut),
+/// only on the first mutation through the window while it is shared
+#[cfg(feature = "ecow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecow")))]
+impl Offset for ecow::EcoString {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        let _ = &self[i..];
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        let _ = &self[i..];
+        create(self, i)
+    }
+}
+
+/// Unlike [`Rc<V>`], only the touched chunks are copied on mutation while shared
+#[cfg(feature = "imbl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "imbl")))]
+impl<T: Clone, P: imbl::shared_ptr::SharedPointerKind> Offset for imbl::GenericVector<T, P> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "file-vec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-vec")))]
+impl<T: crate::Pod> Offset for crate::FileVec<T> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: crate::Pod> Offset for crate::MmapVec<T> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tinyvec")))]
+impl<A: tinyvec::Array> Offset for tinyvec::ArrayVec<A> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tinyvec")))]
+impl<'s, T: Default> Offset for tinyvec::SliceVec<'s, T> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "tinyvec", feature = "alloc"))))]
+impl<A: tinyvec::Array> Offset for tinyvec::TinyVec<A> {
+    type Output = Self;
+    type OutputMut = Self;
+
+    #[track_caller]
+    fn offset(self, i: usize) -> OffsetVec<Self::Output> {
+        create(self, i)
+    }
+
+    #[track_caller]
+    fn offset_mut(&mut self, i: usize) -> OffsetVec<&mut Self::Output> {
+        create(self, i)
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
     use alloc::vec;
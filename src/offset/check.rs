@@ -1,6 +1,9 @@
+#[cfg(feature = "alloc")]
 use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
 
-use crate::{OffsetVec, VecLike};
+use crate::{OffsetVec, SeqLike};
 
 pub trait OffsetCheckRef {
     #[inline]
@@ -32,6 +35,8 @@ impl<T: OffsetCheckRef + ?Sized> OffsetCheckRef for &mut T {
         (**self).offset_check_ref();
     }
 }
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<T: OffsetCheckRef + ?Sized> OffsetCheckRef for Box<T> {
     #[inline]
     #[track_caller]
@@ -39,10 +44,24 @@ impl<T: OffsetCheckRef + ?Sized> OffsetCheckRef for Box<T> {
         (**self).offset_check_ref();
     }
 }
+#[cfg(feature = "allocator_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T: OffsetCheckRef + ?Sized, A: Allocator> OffsetCheckRef for Box<T, A> {
+    #[inline]
+    #[track_caller]
+    fn offset_check_ref(&self) {
+        (**self).offset_check_ref();
+    }
+}
 
 impl<T> OffsetCheckRef for [T] {}
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<T> OffsetCheckRef for Vec<T> {}
-impl<V: VecLike> OffsetCheckRef for OffsetVec<V> {
+#[cfg(feature = "allocator_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T, A: Allocator> OffsetCheckRef for Vec<T, A> {}
+impl<V: SeqLike> OffsetCheckRef for OffsetVec<V> {
     #[inline]
     #[track_caller]
     fn offset_check_ref(&self) {
@@ -0,0 +1,331 @@
+use core::{fmt, mem, ops::{Deref, Index, Range, RangeFrom, RangeFull, RangeTo}, str};
+use std::ffi::{OsStr, OsString};
+
+/// Check `index` is a valid split point of [`OsStr::as_encoded_bytes`]
+///
+/// Valid split points are the ends, and immediately before or after
+/// any valid non-empty UTF-8 substring
+pub(crate) fn is_os_boundary(bytes: &[u8], index: usize) -> bool {
+    if index == 0 || index == bytes.len() {
+        return true;
+    }
+    if index > bytes.len() {
+        return false;
+    }
+    if bytes[index-1].is_ascii() || bytes[index].is_ascii() {
+        return true;
+    }
+
+    let (before, after) = bytes.split_at(index);
+
+    // UTF-8 takes at most 4 bytes per codepoint
+    let after = after.get(..4).unwrap_or(after);
+    match str::from_utf8(after) {
+        Ok(_) => return true,
+        Err(err) if err.valid_up_to() != 0 => return true,
+        Err(_) => (),
+    }
+
+    (2..=4.min(index)).any(|len| {
+        str::from_utf8(&before[index-len..]).is_ok()
+    })
+}
+
+/// Length of the first unit of non-empty encoded bytes
+///
+/// A unit is a char, or a maximal run of non UTF-8 bytes
+pub(crate) fn unit_len(bytes: &[u8]) -> usize {
+    let mut chunks = bytes.utf8_chunks();
+    let Some(first) = chunks.next() else { return 0 };
+
+    if let Some(ch) = first.valid().chars().next() {
+        return ch.len_utf8();
+    }
+
+    let mut len = first.invalid().len();
+    for chunk in chunks {
+        if !chunk.valid().is_empty() {
+            break;
+        }
+        len += chunk.invalid().len();
+    }
+    len
+}
+
+/// Length of the last unit of non-empty encoded bytes
+pub(crate) fn last_unit_len(bytes: &[u8]) -> usize {
+    let len = bytes.len();
+    match bytes.last() {
+        None => return 0,
+        Some(b) if b.is_ascii() => return 1,
+        Some(_) => (),
+    }
+
+    for ch_len in 2..=4.min(len) {
+        if str::from_utf8(&bytes[len-ch_len..]).is_ok() {
+            return ch_len;
+        }
+    }
+
+    let mut i = 0;
+    loop {
+        let unit_len = unit_len(&bytes[i..]);
+        if i + unit_len == len {
+            break unit_len;
+        }
+        i += unit_len;
+    }
+}
+
+/// Iterate units start index and length
+pub(crate) fn units(bytes: &[u8]) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut i = 0;
+    core::iter::from_fn(move || {
+        let len = unit_len(&bytes[i..]);
+        (len != 0).then(|| {
+            i += len;
+            i-len..i
+        })
+    })
+}
+
+/// # Safety
+/// - `bytes` must be split from [`OsStr::as_encoded_bytes`] on valid boundaries
+#[inline]
+pub(crate) unsafe fn os_str(bytes: &[u8]) -> &OsStr {
+    // SAFETY: guaranteed by caller
+    unsafe { OsStr::from_encoded_bytes_unchecked(bytes) }
+}
+
+/// Truncate `s` into `len` bytes
+///
+/// # Panics
+/// - `len` is not on a valid boundary
+#[track_caller]
+pub(crate) fn truncate(s: &mut OsString, len: usize) {
+    let slice = OsSlice::new(s);
+    if len >= slice.len() {
+        return;
+    }
+    slice.check_boundary(len);
+
+    let mut bytes = mem::take(s).into_encoded_bytes();
+    bytes.truncate(len);
+    // SAFETY: split on valid boundary
+    *s = unsafe { OsString::from_encoded_bytes_unchecked(bytes) };
+}
+
+/// Replace `range` of `s` into `with`
+///
+/// # Panics
+/// - `range` is not on a valid boundary
+#[track_caller]
+pub(crate) fn replace(s: &mut OsString, range: Range<usize>, with: &OsStr) {
+    let tail = OsSlice::new(s)[range.end..].to_os_string();
+    truncate(s, range.start);
+    s.push(with);
+    s.push(tail);
+}
+
+/// [`OsStr`] that can be sliced on the boundaries [`OsStr`] accepts
+///
+/// Indices are counted in [`OsStr::as_encoded_bytes`],
+/// and must be at the ends, or immediately before or after
+/// any valid non-empty UTF-8 substring
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OsSlice {
+    inner: OsStr,
+}
+
+impl OsSlice {
+    #[inline]
+    pub fn new<S: AsRef<OsStr> + ?Sized>(s: &S) -> &Self {
+        let s = s.as_ref();
+        // SAFETY: `OsSlice` is repr(transparent) over `OsStr`
+        unsafe { &*(s as *const OsStr as *const Self) }
+    }
+
+    #[inline]
+    pub fn new_mut(s: &mut OsStr) -> &mut Self {
+        // SAFETY: `OsSlice` is repr(transparent) over `OsStr`
+        unsafe { &mut *(s as *mut OsStr as *mut Self) }
+    }
+
+    #[inline]
+    pub fn as_os_str(&self) -> &OsStr {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn as_mut_os_str(&mut self) -> &mut OsStr {
+        &mut self.inner
+    }
+
+    /// Get length of encoded bytes
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// `self.len() == 0`
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Check `index` is a split point accepted by [`OsStr`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use offset_vec::OsSlice;
+    ///
+    /// let s = OsSlice::new("a你");
+    /// assert!(s.is_boundary(1));
+    /// assert!(!s.is_boundary(2));
+    /// assert!(s.is_boundary(4));
+    /// assert!(!s.is_boundary(5));
+    /// ```
+    pub fn is_boundary(&self, index: usize) -> bool {
+        is_os_boundary(self.inner.as_encoded_bytes(), index)
+    }
+
+    #[track_caller]
+    fn check_boundary(&self, index: usize) {
+        if !self.is_boundary(index) {
+            #[cold]
+            #[track_caller]
+            #[inline(never)]
+            fn fail(index: usize, len: usize) -> ! {
+                if index > len {
+                    panic!("index {index} out of length (is {len})");
+                }
+                panic!("byte index {index} is not an OsStr boundary");
+            }
+            fail(index, self.len())
+        }
+    }
+}
+
+impl Deref for OsSlice {
+    type Target = OsStr;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl AsRef<OsStr> for OsSlice {
+    fn as_ref(&self) -> &OsStr {
+        &self.inner
+    }
+}
+
+impl PartialEq<str> for OsSlice {
+    fn eq(&self, other: &str) -> bool {
+        self.inner == *other
+    }
+}
+impl PartialEq<&str> for OsSlice {
+    fn eq(&self, other: &&str) -> bool {
+        self.inner == **other
+    }
+}
+impl PartialEq<OsStr> for OsSlice {
+    fn eq(&self, other: &OsStr) -> bool {
+        self.inner == *other
+    }
+}
+
+impl fmt::Display for OsSlice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner.to_string_lossy(), f)
+    }
+}
+
+impl fmt::Debug for OsSlice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl Index<Range<usize>> for OsSlice {
+    type Output = Self;
+
+    #[track_caller]
+    fn index(&self, index: Range<usize>) -> &Self::Output {
+        if index.start > index.end {
+            #[cold]
+            #[track_caller]
+            #[inline(never)]
+            fn fail(index: usize, end: usize) -> ! {
+                panic!("range index starts at {index} but ends at {end}");
+            }
+            fail(index.start, index.end)
+        }
+        self.check_boundary(index.start);
+        self.check_boundary(index.end);
+
+        let bytes = &self.inner.as_encoded_bytes()[index];
+        // SAFETY: split on valid boundaries
+        Self::new(unsafe { os_str(bytes) })
+    }
+}
+
+impl Index<RangeTo<usize>> for OsSlice {
+    type Output = Self;
+
+    #[track_caller]
+    fn index(&self, index: RangeTo<usize>) -> &Self::Output {
+        &self[0..index.end]
+    }
+}
+
+impl Index<RangeFrom<usize>> for OsSlice {
+    type Output = Self;
+
+    #[track_caller]
+    fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
+        &self[index.start..self.len()]
+    }
+}
+
+impl Index<RangeFull> for OsSlice {
+    type Output = Self;
+
+    fn index(&self, _: RangeFull) -> &Self::Output {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boundary() {
+        let datas = [
+            ("", 0, true),
+            ("a", 1, true),
+            ("a", 2, false),
+            ("你好", 3, true),
+            ("你好", 1, false),
+            ("你好", 4, false),
+        ];
+
+        for (s, i, ok) in datas {
+            assert_eq!(is_os_boundary(s.as_bytes(), i), ok, "{s:?}, {i}");
+        }
+    }
+
+    #[test]
+    fn units_len() {
+        assert_eq!(unit_len(b""), 0);
+        assert_eq!(unit_len("你a".as_bytes()), 3);
+        assert_eq!(unit_len(b"\xff\xfea"), 2);
+        assert_eq!(last_unit_len("a你".as_bytes()), 3);
+        assert_eq!(last_unit_len(b"a\xff\xfe"), 2);
+        assert_eq!(units(b"a\xff\xfe\xc3\xa9").collect::<std::vec::Vec<_>>(), [0..1, 1..3, 3..5]);
+    }
+}
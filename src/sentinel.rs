@@ -0,0 +1,245 @@
+use alloc::vec::{self, Vec};
+use core::{
+    ffi::{CStr, FromBytesWithNulError},
+    iter::FusedIterator,
+    ops::{Deref, DerefMut, Range},
+};
+
+use crate::{OffsetVec, SliceLike};
+
+/// [`Vec`] that always keeps a sentinel element at the end,
+/// like the NUL byte of C strings
+///
+/// All [`SliceLike`] methods hide the sentinel,
+/// use [`as_slice_with_sentinel`] to expose it
+///
+/// # Examples
+///
+/// ```
+/// use offset_vec::{Offset, SentinelVec};
+///
+/// let mut args = SentinelVec::<u8>::default();
+/// let mut arg1 = args.offset_mut(0);
+///
+/// arg1.extend(*b"foo");
+/// assert_eq!(arg1, b"foo");
+///
+/// let mut arg2 = args.offset_mut(3);
+/// arg2.extend(*b"bar");
+/// arg2.pop();
+///
+/// assert_eq!(args, *b"fooba");
+/// assert_eq!(args.as_slice_with_sentinel(), b"fooba\0");
+/// assert_eq!(args.as_c_str().unwrap(), c"fooba");
+/// ```
+///
+/// [`as_slice_with_sentinel`]: SentinelVec::as_slice_with_sentinel
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SentinelVec<T> {
+    pub(crate) vec: Vec<T>,
+}
+
+impl<T> SentinelVec<T> {
+    /// Create a empty vector terminated by `sentinel`
+    pub fn new(sentinel: T) -> Self {
+        Self::from_vec(Vec::new(), sentinel)
+    }
+
+    /// Create a empty vector terminated by `sentinel`,
+    /// with at least `capacity` elements excluding sentinel
+    pub fn with_capacity(capacity: usize, sentinel: T) -> Self {
+        Self::from_vec(Vec::with_capacity(capacity+1), sentinel)
+    }
+
+    /// Append `sentinel` to `vec`
+    pub fn from_vec(mut vec: Vec<T>, sentinel: T) -> Self {
+        vec.push(sentinel);
+        Self { vec }
+    }
+
+    /// Get the sentinel
+    pub fn sentinel(&self) -> &T {
+        self.vec.last().unwrap()
+    }
+
+    /// Get elements including the trailing sentinel
+    pub fn as_slice_with_sentinel(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Get the pointer to elements, followed by the sentinel
+    pub fn as_ptr(&self) -> *const T {
+        self.vec.as_ptr()
+    }
+
+    /// Get elements without sentinel
+    pub fn as_slice(&self) -> &[T] {
+        let len = self.len();
+        &self.vec[..len]
+    }
+
+    /// Get mutable elements without sentinel
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        &mut self.vec[..len]
+    }
+
+    /// Get length without sentinel
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len() - 1
+    }
+
+    /// `self.len() == 0`
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Split into elements and sentinel
+    pub fn into_parts(mut self) -> (Vec<T>, T) {
+        let sentinel = self.vec.pop().unwrap();
+        (self.vec, sentinel)
+    }
+
+    /// Get elements including the trailing sentinel
+    pub fn into_vec_with_sentinel(self) -> Vec<T> {
+        self.vec
+    }
+}
+
+impl SentinelVec<u8> {
+    /// Get [`CStr`], fails when the sentinel is not NUL
+    /// or the elements contain NUL
+    pub fn as_c_str(&self) -> Result<&CStr, FromBytesWithNulError> {
+        CStr::from_bytes_with_nul(&self.vec)
+    }
+}
+
+impl<T: Default> Default for SentinelVec<T> {
+    /// Terminated by [`T::default()`](Default::default), e.g NUL for bytes
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Deref for SentinelVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+impl<T> DerefMut for SentinelVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> AsRef<SentinelVec<T>> for SentinelVec<T> {
+    fn as_ref(&self) -> &SentinelVec<T> {
+        self
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for SentinelVec<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for SentinelVec<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T> Extend<T> for SentinelVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let len = self.len();
+        self.vec.splice(len..len, iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for SentinelVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Draining iterator of [`SentinelVec`]
+///
+/// The vector ends with a clone of the sentinel while draining,
+/// leaking the iterator loses the tail elements but keeps the sentinel
+#[derive(Debug)]
+pub struct SentinelDrain<'a, T> {
+    vec: &'a mut Vec<T>,
+    iter: vec::IntoIter<T>,
+    tail: Vec<T>,
+}
+
+impl<'a, T: Clone> SentinelDrain<'a, T> {
+    pub(crate) fn new(vec: &'a mut Vec<T>, range: Range<usize>) -> Self {
+        let sentinel = vec.last().unwrap().clone();
+        let tail = vec.split_off(range.end);
+        let iter = vec.split_off(range.start).into_iter();
+        vec.push(sentinel);
+        Self { vec, iter, tail }
+    }
+}
+
+impl<T> Iterator for SentinelDrain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<T> DoubleEndedIterator for SentinelDrain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+impl<T> ExactSizeIterator for SentinelDrain<'_, T> {}
+impl<T> FusedIterator for SentinelDrain<'_, T> {}
+
+impl<T> Drop for SentinelDrain<'_, T> {
+    fn drop(&mut self) {
+        self.vec.pop();
+        self.vec.append(&mut self.tail);
+    }
+}
+
+impl<T, V> OffsetVec<V>
+where V: SliceLike<Elem = T> + AsRef<SentinelVec<T>>,
+{
+    /// Get offset elements including the trailing sentinel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use offset_vec::{Offset, SentinelVec};
+    ///
+    /// let mut vec = SentinelVec::from_vec(vec![1, 2, 3], 0);
+    /// let vec1 = vec.offset_mut(1);
+    ///
+    /// assert_eq!(vec1, [2, 3]);
+    /// assert_eq!(vec1.as_slice_with_sentinel(), [2, 3, 0]);
+    /// ```
+    pub fn as_slice_with_sentinel(&self) -> &[T] {
+        &self.vec.as_ref().as_slice_with_sentinel()[self.offset..]
+    }
+}
+
+impl<V> OffsetVec<V>
+where V: SliceLike<Elem = u8> + AsRef<SentinelVec<u8>>,
+{
+    /// Get offset [`CStr`], fails when the sentinel is not NUL
+    /// or the elements contain NUL
+    pub fn as_c_str(&self) -> Result<&CStr, FromBytesWithNulError> {
+        CStr::from_bytes_with_nul(self.as_slice_with_sentinel())
+    }
+}
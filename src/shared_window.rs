@@ -0,0 +1,369 @@
+use alloc::sync::Arc;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, Range, RangeBounds},
+};
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{util::normalize_range, OffsetVec, SeqLike, Slice};
+
+/// Buffers shared by [`SharedWindow`], e.g `[T]`, `str` and `Vec<T>`
+pub trait SharedBuf {
+    type Slice: ?Sized + Slice;
+
+    fn as_shared_slice(&self) -> &Self::Slice;
+}
+
+impl<T> SharedBuf for [T] {
+    type Slice = [T];
+
+    #[inline]
+    fn as_shared_slice(&self) -> &Self::Slice {
+        self
+    }
+}
+
+impl SharedBuf for str {
+    type Slice = str;
+
+    #[inline]
+    fn as_shared_slice(&self) -> &Self::Slice {
+        self
+    }
+}
+
+impl<T> SharedBuf for Vec<T> {
+    type Slice = [T];
+
+    #[inline]
+    fn as_shared_slice(&self) -> &Self::Slice {
+        self
+    }
+}
+
+impl SharedBuf for String {
+    type Slice = str;
+
+    #[inline]
+    fn as_shared_slice(&self) -> &Self::Slice {
+        self
+    }
+}
+
+/// Cheaply clonable read only window over a shared buffer,
+/// e.g `Arc<str>`, `Arc<[T]>` and `Arc<Vec<T>>`
+///
+/// Derefs to the window slice [`B::Slice`](SharedBuf::Slice), the buffer is never copied,
+/// [`split_to`], [`split_off`] and [`slice`] only share the buffer with narrower windows
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use offset_vec::SharedWindow;
+///
+/// let src: Arc<str> = "let x = 1;".into();
+/// let mut rest = SharedWindow::new(src);
+///
+/// let kw = rest.split_to(3);
+/// let name = rest.slice(1..2);
+/// assert_eq!(kw, "let");
+/// assert_eq!(name, "x");
+/// assert_eq!(name.origin_range(), 4..5);
+/// assert_eq!(rest, " x = 1;");
+///
+/// let semi = rest.split_off(rest.len()-1);
+/// assert_eq!(semi, ";");
+/// assert_eq!(rest, " x = 1");
+/// ```
+///
+/// Over `Arc<Vec<T>>`, without copying into `Arc<[T]>`:
+///
+/// ```
+/// use std::sync::Arc;
+/// use offset_vec::SharedWindow;
+///
+/// let buf = Arc::new(vec![1, 2, 3, 4]);
+/// let window = SharedWindow::new(buf.clone()).slice(1..);
+/// assert_eq!(window, [2, 3, 4]);
+/// assert!(Arc::ptr_eq(window.origin(), &buf));
+/// ```
+///
+/// [`split_to`]: SharedWindow::split_to
+/// [`split_off`]: SharedWindow::split_off
+/// [`slice`]: SharedWindow::slice
+pub struct SharedWindow<B: ?Sized + SharedBuf> {
+    buf: Arc<B>,
+    start: usize,
+    end: usize,
+}
+
+impl<B: ?Sized + SharedBuf> SharedWindow<B> {
+    /// Window over the whole buffer
+    pub fn new(buf: Arc<B>) -> Self {
+        let end = buf.as_shared_slice().len();
+        Self { buf, start: 0, end }
+    }
+
+    /// Window over the range of the buffer
+    ///
+    /// # Panics
+    /// - `range` out of bounds
+    /// - `range` is not on the boundaries of `B::Slice`, e.g char boundaries of [`str`]
+    #[track_caller]
+    pub fn from_range<R: RangeBounds<usize>>(buf: Arc<B>, range: R) -> Self {
+        Self::new(buf).slice(range)
+    }
+
+    /// Get the shared original buffer
+    #[inline]
+    pub fn origin(&self) -> &Arc<B> {
+        &self.buf
+    }
+
+    #[inline]
+    pub fn into_origin(self) -> Arc<B> {
+        self.buf
+    }
+
+    /// Range of the window in the original buffer
+    #[inline]
+    pub fn origin_range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Get offset of the window in the original buffer
+    #[inline]
+    pub fn origin_offset(&self) -> usize {
+        self.start
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &B::Slice {
+        &self.buf.as_shared_slice()[self.start..self.end]
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Nested window over the range of this window, shares the buffer
+    ///
+    /// # Panics
+    /// - `range` out of bounds
+    /// - `range` is not on the boundaries of `B::Slice`, e.g char boundaries of [`str`]
+    #[track_caller]
+    #[must_use]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let Range { start, end } = normalize_range(range, self.len());
+        let _ = &self.as_slice()[start..end];
+        Self {
+            buf: self.buf.clone(),
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+
+    /// Split the window into `[0, at)` and `[at, len)`, return the former
+    ///
+    /// # Panics
+    /// - `at > len`
+    /// - `at` is not on the boundaries of `B::Slice`, e.g char boundaries of [`str`]
+    #[track_caller]
+    #[must_use = "use `.slice(at..)` if you don't need the other half"]
+    pub fn split_to(&mut self, at: usize) -> Self {
+        let head = self.slice(..at);
+        self.start = head.end;
+        head
+    }
+
+    /// Split the window into `[0, at)` and `[at, len)`, return the latter
+    ///
+    /// # Panics
+    /// - `at > len`
+    /// - `at` is not on the boundaries of `B::Slice`, e.g char boundaries of [`str`]
+    #[track_caller]
+    #[must_use = "use `.slice(..at)` if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let tail = self.slice(at..);
+        self.end = tail.start;
+        tail
+    }
+}
+
+impl<B: ?Sized + SharedBuf> Clone for SharedWindow<B> {
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<B: ?Sized + SharedBuf> From<Arc<B>> for SharedWindow<B> {
+    fn from(buf: Arc<B>) -> Self {
+        Self::new(buf)
+    }
+}
+
+/// Share the buffer of the window, e.g from [`Offset::offset`](crate::Offset::offset)
+impl<V: SharedBuf + SeqLike + Clone> From<OffsetVec<Arc<V>>> for SharedWindow<V> {
+    fn from(window: OffsetVec<Arc<V>>) -> Self {
+        let offset = window.origin_offset();
+        Self::from_range(window.into_origin_vec(), offset..)
+    }
+}
+
+impl<B: ?Sized + SharedBuf> Deref for SharedWindow<B> {
+    type Target = B::Slice;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<B: ?Sized + SharedBuf> AsRef<B::Slice> for SharedWindow<B> {
+    fn as_ref(&self) -> &B::Slice {
+        self
+    }
+}
+
+impl<B: ?Sized + SharedBuf> fmt::Debug for SharedWindow<B> where B::Slice: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<B: ?Sized + SharedBuf> fmt::Display for SharedWindow<B> where B::Slice: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<B: ?Sized + SharedBuf> Hash for SharedWindow<B> where B::Slice: Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<B1, B2> PartialEq<SharedWindow<B2>> for SharedWindow<B1>
+where B1: ?Sized + SharedBuf,
+      B2: ?Sized + SharedBuf<Slice = B1::Slice>,
+      B1::Slice: PartialEq,
+{
+    fn eq(&self, other: &SharedWindow<B2>) -> bool {
+        **self == **other
+    }
+}
+
+impl<B: ?Sized + SharedBuf> Eq for SharedWindow<B> where B::Slice: Eq { }
+
+impl<B: ?Sized + SharedBuf> PartialOrd for SharedWindow<B> where B::Slice: PartialOrd {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<B: ?Sized + SharedBuf> Ord for SharedWindow<B> where B::Slice: Ord {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: PartialEq, B: ?Sized + SharedBuf<Slice = [T]>> PartialEq<[T]> for SharedWindow<B> {
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+impl<T: PartialEq, B: ?Sized + SharedBuf<Slice = [T]>> PartialEq<&[T]> for SharedWindow<B> {
+    fn eq(&self, other: &&[T]) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialEq, B: ?Sized + SharedBuf<Slice = [T]>, const N: usize> PartialEq<[T; N]> for SharedWindow<B> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        **self == *other
+    }
+}
+impl<T: PartialEq, B: ?Sized + SharedBuf<Slice = [T]>, const N: usize> PartialEq<&[T; N]> for SharedWindow<B> {
+    fn eq(&self, other: &&[T; N]) -> bool {
+        **self == **other
+    }
+}
+
+impl<B: ?Sized + SharedBuf<Slice = str>> PartialEq<str> for SharedWindow<B> {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+impl<B: ?Sized + SharedBuf<Slice = str>> PartialEq<&str> for SharedWindow<B> {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+impl<'a, T: 'a, B: ?Sized + SharedBuf<Slice = [T]>> IntoIterator for &'a SharedWindow<B> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use super::*;
+
+    #[test]
+    fn nested_windows() {
+        let buf: Arc<[u8]> = Arc::from(&b"0123456789"[..]);
+        let mut rest = SharedWindow::new(buf.clone());
+        let head = rest.split_to(2);
+        let inner = rest.slice(2..6);
+        let nested = inner.slice(1..);
+        assert_eq!(head, *b"01");
+        assert_eq!(nested, *b"567");
+        assert_eq!(nested.origin_range(), 5..8);
+        assert!(Arc::ptr_eq(nested.origin(), &buf));
+        assert_eq!(Arc::strong_count(&buf), 5);
+    }
+
+    #[test]
+    fn from_offset_vec() {
+        use crate::Offset;
+
+        let buf = Arc::new(String::from("key=value"));
+        let window: SharedWindow<String> = buf.clone().offset(4).into();
+        assert_eq!(window, "value");
+        assert!(Arc::ptr_eq(window.origin(), &buf));
+
+        let window: SharedWindow<Vec<u8>> = Arc::new(vec![1, 2, 3]).offset(1).into();
+        assert_eq!(window, [2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_on_char_boundary() {
+        let mut window = SharedWindow::<str>::new("你好".into());
+        let _ = window.split_to(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_out_of_bounds() {
+        let mut window = SharedWindow::<[i32]>::new(Arc::new([1, 2]));
+        let _ = window.split_off(3);
+    }
+}
@@ -1,9 +1,12 @@
-use core::ops::{IndexMut, Range, RangeFrom, RangeTo};
+use core::ops::{Index, IndexMut, Range, RangeFrom, RangeTo};
+
+#[cfg(feature = "alloc")]
+use crate::Utf16Str;
 
 pub trait Slice
-    : IndexMut<Range<usize>, Output = Self>
-    + IndexMut<RangeTo<usize>, Output = Self>
-    + IndexMut<RangeFrom<usize>, Output = Self>
+    : Index<Range<usize>, Output = Self>
+    + Index<RangeTo<usize>, Output = Self>
+    + Index<RangeFrom<usize>, Output = Self>
 {
     fn len(&self) -> usize;
 
@@ -15,6 +18,23 @@ pub trait Slice
     fn transform_index(&self, index: usize) -> usize;
 }
 
+/// [`Slice`] that can also be mutably sub-sliced
+pub trait SliceMut
+    : Slice
+    + IndexMut<Range<usize>>
+    + IndexMut<RangeTo<usize>>
+    + IndexMut<RangeFrom<usize>>
+{
+}
+
+impl<S> SliceMut for S
+where S: ?Sized + Slice
+       + IndexMut<Range<usize>>
+       + IndexMut<RangeTo<usize>>
+       + IndexMut<RangeFrom<usize>>,
+{
+}
+
 impl<T> Slice for [T] {
     #[inline]
     fn len(&self) -> usize {
@@ -35,3 +55,26 @@ impl Slice for str {
         crate::util::transform_char_index(self, index)
     }
 }
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Slice for Utf16Str {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn transform_index(&self, index: usize) -> usize {
+        self[..index].chars().count()
+    }
+}
+#[cfg(feature = "std")]
+impl Slice for crate::OsSlice {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn transform_index(&self, index: usize) -> usize {
+        crate::os_str::units(self[..index].as_encoded_bytes()).count()
+    }
+}
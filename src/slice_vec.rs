@@ -0,0 +1,358 @@
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut, RangeBounds},
+};
+
+use crate::{array_vec::capacity_overflow, uninit::{self, Prefix, UninitDrain}, CapacityError};
+
+/// Vector over a caller-owned buffer and a tracked length, never allocate
+///
+/// Elements `buf[..len]` are owned by the vector and dropped with it,
+/// growing beyond the buffer fails through [`try_push`](SliceVec::try_push)
+/// and [`try_insert`](SliceVec::try_insert)
+///
+/// # Examples
+///
+/// ```
+/// use core::mem::MaybeUninit;
+/// use offset_vec::{Offset, SliceVec};
+///
+/// let mut buf = [const { MaybeUninit::uninit() }; 4];
+/// let mut vec = SliceVec::new(&mut buf);
+/// vec.extend(["a", "b"]);
+///
+/// let mut vec1 = vec.offset_mut(1);
+/// assert_eq!(vec1.try_push("c"), Ok(()));
+/// assert_eq!(vec1.try_push("d"), Ok(()));
+/// assert!(vec1.try_push("e").is_err());
+///
+/// assert_eq!(vec, ["a", "b", "c", "d"]);
+/// ```
+pub struct SliceVec<'a, T> {
+    buf: &'a mut [MaybeUninit<T>],
+    len: usize,
+}
+
+impl<'a, T> SliceVec<'a, T> {
+    /// Create a empty vector over `buf`
+    #[inline]
+    pub fn new(buf: &'a mut [MaybeUninit<T>]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Create a vector over `buf`, taking ownership of `buf[..len]`
+    ///
+    /// # Safety
+    /// - `len <= buf.len()`
+    /// - `buf[..len]` must be initialized,
+    ///   and must not be dropped or read by the caller after
+    #[inline]
+    pub unsafe fn from_parts(buf: &'a mut [MaybeUninit<T>], len: usize) -> Self {
+        debug_assert!(len <= buf.len());
+        Self { buf, len }
+    }
+
+    /// Create a vector over initialized `slice`, the first `len` elements are taken
+    ///
+    /// # Panics
+    /// - `len > slice.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use offset_vec::{Offset, SliceVec};
+    ///
+    /// let mut buf = [0; 5];
+    /// let mut vec = SliceVec::from_init(&mut buf, 2);
+    /// vec.offset_mut(2).extend([3, 4]);
+    ///
+    /// assert_eq!(vec, [0, 0, 3, 4]);
+    /// drop(vec);
+    /// assert_eq!(buf, [0, 0, 3, 4, 0]);
+    /// ```
+    #[track_caller]
+    pub fn from_init(slice: &'a mut [T], len: usize) -> Self
+    where T: Copy,
+    {
+        let cap = slice.len();
+        if len > cap {
+            panic!("length (is {len}) should be <= slice length (is {cap})");
+        }
+        // SAFETY: `MaybeUninit<T>` has same layout as `T`,
+        // only initialized values are written, and `T: Copy` needs no drop
+        let buf = unsafe { &mut *(slice as *mut [T] as *mut [MaybeUninit<T>]) };
+        Self { buf, len }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `self.len() == 0`
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `self.len() == self.capacity()`
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == self.buf.len()
+    }
+
+    /// Length of the buffer
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// `self.capacity() - self.len()`
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.buf.len() - self.len
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: `buf[..len]` is initialized
+        unsafe { uninit::as_slice(self.buf, self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: `buf[..len]` is initialized
+        unsafe { uninit::as_mut_slice(self.buf, self.len) }
+    }
+
+    /// Release the elements without dropping them
+    pub fn into_slice(self) -> &'a mut [T] {
+        let mut this = ManuallyDrop::new(self);
+        let len = this.len;
+        let buf = mem::take(&mut this.buf);
+        // SAFETY: `buf[..len]` is initialized
+        unsafe { uninit::as_mut_slice(buf, len) }
+    }
+
+    #[inline]
+    fn prefix(&mut self) -> Prefix<'_, T> {
+        // SAFETY: `buf[..len]` is initialized
+        unsafe { Prefix::new(self.buf, &mut self.len) }
+    }
+
+    /// Push a value, return the error when the buffer is full
+    pub fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.prefix().try_push(value)
+    }
+
+    /// Push a value
+    ///
+    /// # Panics
+    /// - the buffer is full
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            capacity_overflow(self.capacity())
+        }
+    }
+
+    /// Insert a value, return the error when the buffer is full
+    ///
+    /// # Panics
+    /// - `index > len`
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.prefix().try_insert(index, element)
+    }
+
+    /// Insert a value
+    ///
+    /// # Panics
+    /// - `index > len`
+    /// - the buffer is full
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: T) {
+        if self.try_insert(index, element).is_err() {
+            capacity_overflow(self.capacity())
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.prefix().pop()
+    }
+
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        self.prefix().remove(index)
+    }
+
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.prefix().swap_remove(index)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.prefix().truncate(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        self.prefix().retain_mut(f);
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    #[track_caller]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> UninitDrain<'_, T> {
+        self.prefix().drain(range)
+    }
+
+    /// Split the buffer into two at `at`, the returned vector owns
+    /// the tail elements and the rest of the buffer
+    ///
+    /// The capacity of `self` is reduced to `at`
+    ///
+    /// # Panics
+    /// - `at > len`
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len;
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        let (head, tail) = mem::take(&mut self.buf).split_at_mut(at);
+        self.buf = head;
+        self.len = at;
+        Self { buf: tail, len: len-at }
+    }
+
+    /// Move all elements of `other` into `self`
+    ///
+    /// # Panics
+    /// - total length exceeds capacity
+    #[track_caller]
+    pub fn append(&mut self, other: &mut Self) {
+        if other.len > self.remaining_capacity() {
+            capacity_overflow(self.capacity())
+        }
+        self.extend(other.drain(..));
+    }
+}
+
+impl<T> Drop for SliceVec<'_, T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SliceVec<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T> Deref for SliceVec<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+impl<T> DerefMut for SliceVec<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for SliceVec<'_, T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+impl<T> AsMut<[T]> for SliceVec<'_, T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T: PartialEq> PartialEq for SliceVec<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl<T: Eq> Eq for SliceVec<'_, T> {}
+impl<T: PartialEq> PartialEq<[T]> for SliceVec<'_, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for SliceVec<'_, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Hash> Hash for SliceVec<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T> Extend<T> for SliceVec<'_, T> {
+    /// # Panics
+    /// - total length exceeds capacity
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|elem| self.push(elem));
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for SliceVec<'_, T> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::rc::Rc;
+
+    #[test]
+    fn drop_elements() {
+        let rc = Rc::new(());
+        let mut buf = [const { MaybeUninit::uninit() }; 4];
+        let mut vec = SliceVec::new(&mut buf);
+        vec.extend([rc.clone(), rc.clone(), rc.clone()]);
+        vec.truncate(2);
+        assert_eq!(Rc::strong_count(&rc), 3);
+
+        let tail = vec.split_off(1);
+        assert_eq!((vec.capacity(), tail.capacity()), (1, 3));
+        drop(tail);
+        assert_eq!(Rc::strong_count(&rc), 2);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn from_init() {
+        let mut buf = [1, 2, 3, 4];
+        let mut vec = SliceVec::from_init(&mut buf, 3);
+        assert_eq!(vec.remove(0), 1);
+        vec.insert(2, 5);
+        assert_eq!(vec.try_push(6), Ok(()));
+        assert_eq!(vec.try_push(7), Err(CapacityError::new(7)));
+        assert_eq!(vec.into_slice(), [2, 3, 5, 6]);
+        assert_eq!(buf, [2, 3, 5, 6]);
+    }
+}
@@ -0,0 +1,230 @@
+//! Conformance checks for [`SeqVecLike`] implementations, the results are compared against [`Vec`]
+//!
+//! Indices are in elements of the model [`Vec`], so string backends must use ASCII samples.
+//! Fixed-capacity backends need a capacity of at least `samples.len() + 2`
+//!
+//! # Examples
+//!
+//! ```
+//! use offset_vec::testing::{check_offset, check_vec_like};
+//!
+//! check_vec_like::<Vec<i32>>(&[1, 2, 3, 4]);
+//! check_offset::<String>(&['a', 'b', 'c']);
+//! ```
+
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::Range};
+
+use crate::{Offset, OffsetVec, SeqVecLike, Truncatable, VecLike};
+
+/// Collect the elements and push them back, works on non-contiguous backends
+fn contents<V: SeqVecLike>(vec: &mut V) -> Vec<V::Elem>
+where V::Elem: Clone,
+{
+    let len = vec.len();
+    let elems: Vec<_> = vec.drain(..).collect();
+    assert_eq!(elems.len(), len, "drain(..) length");
+    assert!(vec.is_empty(), "empty after drain(..)");
+    for elem in &elems {
+        vec.push(elem.clone());
+    }
+    elems
+}
+
+#[track_caller]
+fn check_eq<V: SeqVecLike>(vec: &mut V, model: &[V::Elem], step: &str)
+where V::Elem: Clone + PartialEq + Debug,
+{
+    assert_eq!(vec.len(), model.len(), "len after {step}");
+    assert_eq!(vec.is_empty(), model.is_empty(), "is_empty after {step}");
+    assert_eq!(contents(vec), model, "contents after {step}");
+}
+
+fn filled<V: SeqVecLike + Default>(samples: &[V::Elem]) -> V
+where V::Elem: Clone,
+{
+    let mut vec = V::default();
+    for elem in samples {
+        vec.push(elem.clone());
+    }
+    vec
+}
+
+/// Keep the elements at odd indices, the generic `W` avoids `'static` of concrete [`OffsetVec`]
+fn retain_odd<W: Truncatable>(vec: &mut W) {
+    let mut i = 0;
+    vec.retain(|_| { i += 1; i % 2 == 0 });
+}
+
+fn drain_ranges(len: usize) -> Vec<Range<usize>> {
+    let mut ranges = alloc::vec![0..0, 0..len, len..len];
+    if len >= 1 {
+        ranges.extend([0..1, len-1..len]);
+    }
+    if len >= 2 {
+        ranges.extend([1..len-1, len/2..len/2+1]);
+    }
+    ranges
+}
+
+/// Run the standard battery against `V`, starting from [`Default`] and pushing `samples`
+///
+/// Covers `push`/`pop`, `insert`/`remove` at edges, `drain` ranges,
+/// `split_off`/`append`, `retain`, `resize`, `truncate` and `clear`
+///
+/// # Panics
+/// Panic on the first mismatch against [`Vec`]
+#[track_caller]
+pub fn check_vec_like<V>(samples: &[V::Elem])
+where V: SeqVecLike + Default,
+      V::Elem: Clone + PartialEq + Debug,
+{
+    let mut vec = V::default();
+    check_eq(&mut vec, &[], "default");
+    assert_eq!(vec.pop(), None, "pop of empty");
+
+    let mut vec = filled::<V>(samples);
+    let mut model = samples.to_vec();
+    check_eq(&mut vec, &model, "push");
+    assert!(vec.capacity() >= vec.len(), "capacity less than len");
+
+    assert_eq!(vec.pop(), model.pop(), "pop");
+    check_eq(&mut vec, &model, "pop");
+
+    let Some(first) = samples.first() else { return };
+
+    vec.push(first.clone());
+    model.push(first.clone());
+    vec.insert(0, first.clone());
+    model.insert(0, first.clone());
+    check_eq(&mut vec, &model, "insert at 0");
+    vec.insert(vec.len(), first.clone());
+    model.insert(model.len(), first.clone());
+    check_eq(&mut vec, &model, "insert at len");
+
+    assert_eq!(vec.remove(0), model.remove(0), "remove at 0");
+    let last = model.len()-1;
+    assert_eq!(vec.remove(last), model.remove(last), "remove at len-1");
+    let mid = model.len()/2;
+    assert_eq!(vec.remove(mid), model.remove(mid), "remove at middle");
+    check_eq(&mut vec, &model, "remove");
+
+    for range in drain_ranges(samples.len()) {
+        let mut vec = filled::<V>(samples);
+        let mut model = samples.to_vec();
+        let drained: Vec<_> = vec.drain(range.clone()).collect();
+        assert_eq!(drained, model.drain(range.clone()).collect::<Vec<_>>(), "drain({range:?})");
+        check_eq(&mut vec, &model, "drain");
+    }
+
+    for at in [0, samples.len()/2, samples.len()] {
+        let mut vec = filled::<V>(samples);
+        let mut tail = vec.split_off(at);
+        check_eq(&mut vec, &samples[..at], "split_off");
+        vec.append(&mut tail);
+        check_eq(&mut vec, samples, "append of split_off");
+    }
+
+    let mut vec = filled::<V>(samples);
+    let mut i = 0;
+    vec.retain(|_| { i += 1; i % 2 == 1 });
+    let model: Vec<_> = samples.iter().step_by(2).cloned().collect();
+    check_eq(&mut vec, &model, "retain");
+
+    let mut vec = filled::<V>(samples);
+    let mut model = samples.to_vec();
+    vec.resize(model.len()+2, first.clone());
+    model.resize(model.len()+2, first.clone());
+    check_eq(&mut vec, &model, "resize grow");
+    vec.resize(1, first.clone());
+    model.resize(1, first.clone());
+    check_eq(&mut vec, &model, "resize shrink");
+    vec.resize_with(3, || first.clone());
+    model.resize_with(3, || first.clone());
+    check_eq(&mut vec, &model, "resize_with");
+
+    let mut vec = filled::<V>(samples);
+    vec.truncate(samples.len()+1);
+    check_eq(&mut vec, samples, "truncate beyond len");
+    vec.truncate(samples.len()/2);
+    check_eq(&mut vec, &samples[..samples.len()/2], "truncate");
+    vec.clear();
+    check_eq(&mut vec, &[], "clear");
+}
+
+/// Run the window battery of [`OffsetVec`] against `V` at every offset,
+/// the mutations through the window must keep the prefix
+///
+/// Covers `pop`/`push`, `insert`/`remove`, `split_off`/`append`, `drain`,
+/// `retain`, `resize`, `truncate` and `clear` through the window
+///
+/// # Panics
+/// Panic on the first mismatch against [`Vec`]
+#[track_caller]
+pub fn check_offset<V>(samples: &[V::Elem])
+where V: SeqVecLike + Offset + Default,
+      V::OutputMut: VecLike<Elem = V::Elem>,
+      V::Elem: Clone + PartialEq + Debug,
+{
+    let with_prefix = |offset: usize, model: &[V::Elem]| {
+        let mut whole = samples[..offset].to_vec();
+        whole.extend_from_slice(model);
+        whole
+    };
+
+    for offset in 0..=samples.len() {
+        let mut vec = filled::<V>(samples);
+        let mut model = samples[offset..].to_vec();
+
+        let mut window: OffsetVec<&mut V::OutputMut> = vec.offset_mut(offset);
+        assert_eq!(window.origin_offset(), offset, "origin_offset");
+        assert_eq!(window.len(), model.len(), "window len");
+        assert_eq!(window.is_empty(), model.is_empty(), "window is_empty");
+
+        assert_eq!(window.pop(), model.pop(), "window pop");
+        if let Some(first) = samples.first() {
+            window.push(first.clone());
+            model.push(first.clone());
+            window.insert(0, first.clone());
+            model.insert(0, first.clone());
+            let len = window.len();
+            window.insert(len, first.clone());
+            model.insert(len, first.clone());
+            assert_eq!(window.remove(0), model.remove(0), "window remove at 0");
+        }
+        assert_eq!(window.len(), model.len(), "window len after insert");
+        check_eq(&mut vec, &with_prefix(offset, &model), "window insert");
+
+        let at = model.len()/2;
+        let mut tail = vec.offset_mut(offset).split_off(at);
+        check_eq(&mut vec, &with_prefix(offset, &model[..at]), "window split_off");
+        vec.offset_mut(offset).append(&mut tail);
+        check_eq(&mut vec, &with_prefix(offset, &model), "window append");
+
+        let range = model.len()/2..model.len();
+        let drained: Vec<_> = vec.offset_mut(offset).drain(range.clone()).collect();
+        assert_eq!(drained, model.drain(range).collect::<Vec<_>>(), "window drain");
+        check_eq(&mut vec, &with_prefix(offset, &model), "window drain");
+
+        retain_odd(&mut vec.offset_mut(offset));
+        retain_odd(&mut model);
+        check_eq(&mut vec, &with_prefix(offset, &model), "window retain");
+
+        if let Some(first) = samples.first() {
+            vec.offset_mut(offset).resize(model.len()+2, first.clone());
+            model.resize(model.len()+2, first.clone());
+            check_eq(&mut vec, &with_prefix(offset, &model), "window resize grow");
+            vec.offset_mut(offset).resize(1, first.clone());
+            model.resize(1, first.clone());
+            check_eq(&mut vec, &with_prefix(offset, &model), "window resize shrink");
+        }
+
+        let mut window = vec.offset_mut(offset);
+        window.truncate(model.len()+1);
+        assert_eq!(window.len(), model.len(), "window truncate beyond len");
+        check_eq(&mut vec, &with_prefix(offset, &model), "window mutations");
+
+        vec.offset_mut(offset).clear();
+        check_eq(&mut vec, &samples[..offset], "window clear");
+    }
+}
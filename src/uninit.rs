@@ -0,0 +1,215 @@
+//! Shared operations of initialized prefix `buf[..len]` backends
+
+use core::{fmt, iter::FusedIterator, mem::MaybeUninit, ops::{Range, RangeBounds}, ptr, slice};
+
+use crate::{util::normalize_range, CapacityError};
+
+/// # Safety
+/// - `buf[..len]` must be initialized
+#[inline]
+pub(crate) unsafe fn as_slice<T>(buf: &[MaybeUninit<T>], len: usize) -> &[T] {
+    debug_assert!(len <= buf.len());
+    // SAFETY: guaranteed by caller
+    unsafe { slice::from_raw_parts(buf.as_ptr().cast(), len) }
+}
+
+/// # Safety
+/// - `buf[..len]` must be initialized
+#[inline]
+pub(crate) unsafe fn as_mut_slice<T>(buf: &mut [MaybeUninit<T>], len: usize) -> &mut [T] {
+    debug_assert!(len <= buf.len());
+    // SAFETY: guaranteed by caller
+    unsafe { slice::from_raw_parts_mut(buf.as_mut_ptr().cast(), len) }
+}
+
+/// Initialized prefix `buf[..*len]` of uninitialized buffer
+pub(crate) struct Prefix<'a, T> {
+    buf: &'a mut [MaybeUninit<T>],
+    len: &'a mut usize,
+}
+
+impl<'a, T> Prefix<'a, T> {
+    /// # Safety
+    /// - `buf[..*len]` must be initialized
+    #[inline]
+    pub(crate) unsafe fn new(buf: &'a mut [MaybeUninit<T>], len: &'a mut usize) -> Self {
+        debug_assert!(*len <= buf.len());
+        Self { buf, len }
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: invariant of Prefix
+        unsafe { as_mut_slice(self.buf, *self.len) }
+    }
+
+    pub(crate) fn try_push(self, value: T) -> Result<(), CapacityError<T>> {
+        let Some(slot) = self.buf.get_mut(*self.len) else {
+            return Err(CapacityError::new(value));
+        };
+        slot.write(value);
+        *self.len += 1;
+        Ok(())
+    }
+
+    pub(crate) fn pop(self) -> Option<T> {
+        *self.len = self.len.checked_sub(1)?;
+        // SAFETY: initialized, and length is reduced
+        Some(unsafe { self.buf[*self.len].assume_init_read() })
+    }
+
+    #[track_caller]
+    pub(crate) fn try_insert(mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        let len = *self.len;
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        if len == self.buf.len() {
+            return Err(CapacityError::new(element));
+        }
+        self.buf[len].write(element);
+        *self.len += 1;
+        self.as_mut_slice()[index..].rotate_right(1);
+        Ok(())
+    }
+
+    #[track_caller]
+    pub(crate) fn remove(mut self, index: usize) -> T {
+        let len = *self.len;
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+        self.as_mut_slice()[index..].rotate_left(1);
+        self.pop().unwrap()
+    }
+
+    #[track_caller]
+    pub(crate) fn swap_remove(mut self, index: usize) -> T {
+        let len = *self.len;
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+        self.as_mut_slice().swap(index, len-1);
+        self.pop().unwrap()
+    }
+
+    pub(crate) fn truncate(self, new_len: usize) {
+        let len = *self.len;
+        if new_len >= len {
+            return;
+        }
+        *self.len = new_len;
+        // SAFETY: initialized, and length is reduced
+        unsafe { ptr::drop_in_place(as_mut_slice(&mut self.buf[new_len..], len-new_len)) };
+    }
+
+    pub(crate) fn retain_mut<F: FnMut(&mut T) -> bool>(self, mut f: F) {
+        let len = *self.len;
+        // leak rather than double drop, when `f` panics
+        *self.len = 0;
+
+        let mut write = 0;
+        for read in 0..len {
+            // SAFETY: read < len, it is initialized and not moved
+            let elem = unsafe { self.buf[read].assume_init_mut() };
+            if f(elem) {
+                if read != write {
+                    // SAFETY: write < read, slot of write has been moved or dropped
+                    unsafe {
+                        let src = self.buf[read].as_ptr();
+                        self.buf[write].write(src.read());
+                    }
+                }
+                write += 1;
+            } else {
+                // SAFETY: it is initialized, and will not be read again
+                unsafe { self.buf[read].assume_init_drop() };
+            }
+        }
+
+        *self.len = write;
+    }
+
+    #[track_caller]
+    pub(crate) fn drain<R: RangeBounds<usize>>(self, range: R) -> UninitDrain<'a, T> {
+        let len = *self.len;
+        let range = normalize_range(range, len);
+        // leak rather than double drop, when the drain is leaked
+        *self.len = range.start;
+        UninitDrain {
+            iter: range.clone(),
+            range,
+            tail_end: len,
+            buf: self.buf,
+            len: self.len,
+        }
+    }
+}
+
+/// Draining iterator of fixed-capacity backends, e.g [`ArrayVecLike`](crate::ArrayVecLike)
+pub struct UninitDrain<'a, T> {
+    buf: &'a mut [MaybeUninit<T>],
+    len: &'a mut usize,
+    range: Range<usize>,
+    iter: Range<usize>,
+    tail_end: usize,
+}
+
+impl<T> UninitDrain<'_, T> {
+    /// Get remaining elements
+    pub fn as_slice(&self) -> &[T] {
+        let rest = &self.buf[self.iter.clone()];
+        // SAFETY: remaining elements is initialized
+        unsafe { as_slice(rest, rest.len()) }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UninitDrain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UninitDrain").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T> Iterator for UninitDrain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.iter.next()?;
+        // SAFETY: initialized, and it will not be read again
+        Some(unsafe { self.buf[i].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for UninitDrain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let i = self.iter.next_back()?;
+        // SAFETY: initialized, and it will not be read again
+        Some(unsafe { self.buf[i].assume_init_read() })
+    }
+}
+
+impl<T> ExactSizeIterator for UninitDrain<'_, T> {}
+impl<T> FusedIterator for UninitDrain<'_, T> {}
+
+impl<T> Drop for UninitDrain<'_, T> {
+    fn drop(&mut self) {
+        let rest = &mut self.buf[self.iter.clone()];
+        let rest_len = rest.len();
+        self.iter.start = self.iter.end;
+        // SAFETY: remaining elements is initialized, and will not be read again
+        unsafe { ptr::drop_in_place(as_mut_slice(rest, rest_len)) };
+
+        let Range { start, end } = self.range;
+        let tail_len = self.tail_end - end;
+        // SAFETY: tail is initialized, drained slots has been moved or dropped
+        unsafe {
+            let base = self.buf.as_mut_ptr();
+            ptr::copy(base.add(end), base.add(start), tail_len);
+        }
+        *self.len = start + tail_len;
+    }
+}
@@ -0,0 +1,654 @@
+use alloc::{string::String, vec::{self, Vec}};
+use core::{
+    char::{decode_utf16, DecodeUtf16, DecodeUtf16Error, REPLACEMENT_CHARACTER},
+    fmt::{self, Write},
+    iter::Copied,
+    ops::{Deref, DerefMut, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeTo},
+    slice,
+};
+
+use crate::util::normalize_range;
+
+#[inline]
+fn is_trail_surrogate(unit: u16) -> bool {
+    (0xDC00..0xE000).contains(&unit)
+}
+
+#[inline]
+fn is_lead_surrogate(unit: u16) -> bool {
+    (0xD800..0xDC00).contains(&unit)
+}
+
+/// Borrowed well-formed UTF-16 text, like [`str`] for [`Utf16String`]
+///
+/// All indices are counted in `u16` code units,
+/// and must not split a surrogate pair
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utf16Str {
+    units: [u16],
+}
+
+impl Utf16Str {
+    /// # Safety
+    /// `units` must be well-formed UTF-16
+    #[inline]
+    const unsafe fn from_units_unchecked(units: &[u16]) -> &Self {
+        // SAFETY: `Utf16Str` is repr(transparent) over `[u16]`
+        unsafe { &*(units as *const [u16] as *const Self) }
+    }
+
+    /// # Safety
+    /// `units` must be well-formed UTF-16
+    #[inline]
+    unsafe fn from_units_unchecked_mut(units: &mut [u16]) -> &mut Self {
+        // SAFETY: `Utf16Str` is repr(transparent) over `[u16]`
+        unsafe { &mut *(units as *mut [u16] as *mut Self) }
+    }
+
+    /// Create from UTF-16 code units, fails on unpaired surrogates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use offset_vec::Utf16Str;
+    ///
+    /// let s = Utf16Str::from_units(&[0x61, 0xD83D, 0xDE00]).unwrap();
+    /// assert_eq!(s, "a😀");
+    /// assert!(Utf16Str::from_units(&[0x61, 0xD83D]).is_err());
+    /// ```
+    pub fn from_units(units: &[u16]) -> Result<&Self, DecodeUtf16Error> {
+        decode_utf16(units.iter().copied()).try_for_each(|ch| ch.map(drop))?;
+        // SAFETY: validated above
+        Ok(unsafe { Self::from_units_unchecked(units) })
+    }
+
+    /// Empty UTF-16 string slice
+    #[inline]
+    pub const fn empty() -> &'static Self {
+        // SAFETY: empty units is well-formed
+        unsafe { Self::from_units_unchecked(&[]) }
+    }
+
+    /// Get UTF-16 code units
+    #[inline]
+    pub fn as_units(&self) -> &[u16] {
+        &self.units
+    }
+
+    /// Get length in UTF-16 code units
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    /// `self.len() == 0`
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Check `index` is not inside a surrogate pair and not out of length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use offset_vec::Utf16String;
+    ///
+    /// let s = Utf16String::from("a😀");
+    /// assert!(s.is_char_boundary(0));
+    /// assert!(s.is_char_boundary(1));
+    /// assert!(!s.is_char_boundary(2));
+    /// assert!(s.is_char_boundary(3));
+    /// assert!(!s.is_char_boundary(4));
+    /// ```
+    #[inline]
+    pub fn is_char_boundary(&self, index: usize) -> bool {
+        match self.units.get(index) {
+            Some(&unit) => !is_trail_surrogate(unit),
+            None => index == self.len(),
+        }
+    }
+
+    /// Iterate chars
+    pub fn chars(&self) -> Utf16Chars<'_> {
+        Utf16Chars { iter: decode_utf16(self.units.iter().copied()) }
+    }
+
+    #[track_caller]
+    fn check_char_boundary(&self, index: usize) {
+        if !self.is_char_boundary(index) {
+            #[cold]
+            #[track_caller]
+            #[inline(never)]
+            fn fail(index: usize, len: usize) -> ! {
+                if index > len {
+                    panic!("index {index} out of length (is {len})");
+                }
+                panic!("index {index} is not on a UTF-16 char boundary");
+            }
+            fail(index, self.len())
+        }
+    }
+
+    #[track_caller]
+    fn check_range(&self, range: &Range<usize>) {
+        if range.start > range.end {
+            #[cold]
+            #[track_caller]
+            #[inline(never)]
+            fn fail(index: usize, end: usize) -> ! {
+                panic!("range index starts at {index} but ends at {end}");
+            }
+            fail(range.start, range.end)
+        }
+        self.check_char_boundary(range.start);
+        self.check_char_boundary(range.end);
+    }
+
+    /// Convert to UTF-8 [`String`]
+    pub fn to_utf8(&self) -> String {
+        self.chars().collect()
+    }
+}
+
+impl Default for &Utf16Str {
+    fn default() -> Self {
+        Utf16Str::empty()
+    }
+}
+
+impl PartialEq<str> for Utf16Str {
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+impl PartialEq<&str> for Utf16Str {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl AsRef<[u16]> for Utf16Str {
+    fn as_ref(&self) -> &[u16] {
+        &self.units
+    }
+}
+
+impl fmt::Display for Utf16Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|ch| f.write_char(ch))
+    }
+}
+
+impl fmt::Debug for Utf16Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for ch in self.chars() {
+            if ch == '\'' {
+                f.write_char(ch)?;
+            } else {
+                ch.escape_debug().try_for_each(|ch| f.write_char(ch))?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl Index<Range<usize>> for Utf16Str {
+    type Output = Self;
+
+    #[track_caller]
+    fn index(&self, index: Range<usize>) -> &Self::Output {
+        self.check_range(&index);
+        // SAFETY: range is on char boundaries
+        unsafe { Self::from_units_unchecked(&self.units[index]) }
+    }
+}
+impl IndexMut<Range<usize>> for Utf16Str {
+    #[track_caller]
+    fn index_mut(&mut self, index: Range<usize>) -> &mut Self::Output {
+        self.check_range(&index);
+        // SAFETY: range is on char boundaries
+        unsafe { Self::from_units_unchecked_mut(&mut self.units[index]) }
+    }
+}
+
+impl Index<RangeTo<usize>> for Utf16Str {
+    type Output = Self;
+
+    #[track_caller]
+    fn index(&self, index: RangeTo<usize>) -> &Self::Output {
+        &self[0..index.end]
+    }
+}
+impl IndexMut<RangeTo<usize>> for Utf16Str {
+    #[track_caller]
+    fn index_mut(&mut self, index: RangeTo<usize>) -> &mut Self::Output {
+        &mut self[0..index.end]
+    }
+}
+
+impl Index<RangeFrom<usize>> for Utf16Str {
+    type Output = Self;
+
+    #[track_caller]
+    fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
+        let len = self.len();
+        &self[index.start..len]
+    }
+}
+impl IndexMut<RangeFrom<usize>> for Utf16Str {
+    #[track_caller]
+    fn index_mut(&mut self, index: RangeFrom<usize>) -> &mut Self::Output {
+        let len = self.len();
+        &mut self[index.start..len]
+    }
+}
+
+impl Index<RangeFull> for Utf16Str {
+    type Output = Self;
+
+    fn index(&self, _: RangeFull) -> &Self::Output {
+        self
+    }
+}
+impl IndexMut<RangeFull> for Utf16Str {
+    fn index_mut(&mut self, _: RangeFull) -> &mut Self::Output {
+        self
+    }
+}
+
+impl<'a> IntoIterator for &'a Utf16Str {
+    type Item = char;
+    type IntoIter = Utf16Chars<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
+    }
+}
+
+/// Chars iterator of [`Utf16Str`]
+#[derive(Debug, Clone)]
+pub struct Utf16Chars<'a> {
+    iter: DecodeUtf16<Copied<slice::Iter<'a, u16>>>,
+}
+
+impl Iterator for Utf16Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|ch| ch.unwrap_or(REPLACEMENT_CHARACTER))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Draining iterator of [`Utf16String`]
+#[derive(Debug)]
+pub struct Utf16Drain<'a> {
+    iter: DecodeUtf16<vec::Drain<'a, u16>>,
+}
+
+impl Iterator for Utf16Drain<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|ch| ch.unwrap_or(REPLACEMENT_CHARACTER))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Owned well-formed UTF-16 text, like [`String`] over `Vec<u16>`
+///
+/// All indices are counted in `u16` code units,
+/// and must not split a surrogate pair
+///
+/// # Examples
+///
+/// ```
+/// use offset_vec::{Offset, Utf16String};
+///
+/// let mut s = Utf16String::from("😀foo");
+/// let mut s1 = s.offset_mut(2);
+///
+/// assert_eq!(s1.as_utf16_str(), "foo");
+/// s1.push('😂');
+/// assert_eq!(s1.as_utf16_str(), "foo😂");
+/// assert_eq!(s1.pop(), Some('😂'));
+/// assert_eq!(s, "😀foo");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utf16String {
+    vec: Vec<u16>,
+}
+
+impl Utf16String {
+    #[inline]
+    pub const fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { vec: Vec::with_capacity(capacity) }
+    }
+
+    /// Create from UTF-16 code units, fails on unpaired surrogates
+    pub fn from_units(vec: Vec<u16>) -> Result<Self, DecodeUtf16Error> {
+        Utf16Str::from_units(&vec)?;
+        Ok(Self { vec })
+    }
+
+    /// Create from UTF-16 code units,
+    /// unpaired surrogates replaced by [`REPLACEMENT_CHARACTER`]
+    pub fn from_units_lossy(units: &[u16]) -> Self {
+        decode_utf16(units.iter().copied())
+            .map(|ch| ch.unwrap_or(REPLACEMENT_CHARACTER))
+            .collect()
+    }
+
+    #[inline]
+    pub fn into_units(self) -> Vec<u16> {
+        self.vec
+    }
+
+    #[inline]
+    pub fn as_utf16_str(&self) -> &Utf16Str {
+        // SAFETY: self is always well-formed
+        unsafe { Utf16Str::from_units_unchecked(&self.vec) }
+    }
+
+    #[inline]
+    pub fn as_mut_utf16_str(&mut self) -> &mut Utf16Str {
+        // SAFETY: self is always well-formed
+        unsafe { Utf16Str::from_units_unchecked_mut(&mut self.vec) }
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.vec.reserve_exact(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.vec.shrink_to(min_capacity);
+    }
+
+    pub fn push(&mut self, ch: char) {
+        self.vec.extend_from_slice(ch.encode_utf16(&mut [0; 2]));
+    }
+
+    pub fn push_utf16_str(&mut self, s: &Utf16Str) {
+        self.vec.extend_from_slice(s.as_units());
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars_rev_first()?;
+        self.vec.truncate(self.len() - ch.len_utf16());
+        Some(ch)
+    }
+
+    fn chars_rev_first(&self) -> Option<char> {
+        let units = self.as_units();
+        let start = match units {
+            [.., lead, trail] if is_lead_surrogate(*lead) && is_trail_surrogate(*trail) => units.len() - 2,
+            [] => return None,
+            _ => units.len() - 1,
+        };
+        self[start..].chars().next()
+    }
+
+    /// Remove a char at index (in code units)
+    ///
+    /// # Panics
+    /// - `idx` is not on char boundary or not less than length
+    #[track_caller]
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(ch) = self[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string");
+        };
+        self.vec.drain(idx..idx+ch.len_utf16());
+        ch
+    }
+
+    /// Insert a char before index (in code units)
+    ///
+    /// # Panics
+    /// - `idx` is not on char boundary or out of length
+    #[track_caller]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        self.check_char_boundary(idx);
+        let mut buf = [0; 2];
+        let units = ch.encode_utf16(&mut buf);
+        self.vec.splice(idx..idx, units.iter().copied());
+    }
+
+    /// # Panics
+    /// - `new_len` is not on char boundary
+    #[track_caller]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            self.check_char_boundary(new_len);
+            self.vec.truncate(new_len);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// # Panics
+    /// - `at` is not on char boundary or out of length
+    #[track_caller]
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        self.check_char_boundary(at);
+        Self { vec: self.vec.split_off(at) }
+    }
+
+    /// # Panics
+    /// - range is not on char boundary or out of length
+    #[track_caller]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Utf16Drain<'_> {
+        let range = normalize_range(range, self.len());
+        self.check_range(&range);
+        Utf16Drain { iter: decode_utf16(self.vec.drain(range)) }
+    }
+
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        /// Move the unprocessed tail down and truncate, also when `f` panics
+        struct Guard<'a> {
+            vec: &'a mut Vec<u16>,
+            read: usize,
+            write: usize,
+        }
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                let len = self.vec.len();
+                self.vec.copy_within(self.read..len, self.write);
+                self.vec.truncate(self.write + (len - self.read));
+            }
+        }
+
+        let len = self.len();
+        let mut guard = Guard { vec: &mut self.vec, read: 0, write: 0 };
+
+        while guard.read < len {
+            let units = guard.vec[guard.read..].iter().copied();
+            let ch = decode_utf16(units).next().unwrap().unwrap();
+            let ch_len = ch.len_utf16();
+
+            if f(ch) {
+                guard.vec.copy_within(guard.read..guard.read+ch_len, guard.write);
+                guard.write += ch_len;
+            }
+            guard.read += ch_len;
+        }
+    }
+}
+
+impl Deref for Utf16String {
+    type Target = Utf16Str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_utf16_str()
+    }
+}
+impl DerefMut for Utf16String {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_utf16_str()
+    }
+}
+
+impl PartialEq<str> for Utf16String {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+impl PartialEq<&str> for Utf16String {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+impl fmt::Display for Utf16String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl fmt::Debug for Utf16String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl From<&str> for Utf16String {
+    fn from(value: &str) -> Self {
+        Self { vec: value.encode_utf16().collect() }
+    }
+}
+
+impl From<&Utf16Str> for Utf16String {
+    fn from(value: &Utf16Str) -> Self {
+        Self { vec: value.as_units().into() }
+    }
+}
+
+impl From<Utf16String> for Vec<u16> {
+    fn from(value: Utf16String) -> Self {
+        value.vec
+    }
+}
+
+impl FromIterator<char> for Utf16String {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut s = Self::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl Extend<char> for Utf16String {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|ch| self.push(ch));
+    }
+}
+
+impl<'a> Extend<&'a Utf16Str> for Utf16String {
+    fn extend<I: IntoIterator<Item = &'a Utf16Str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|s| self.push_utf16_str(s));
+    }
+}
+
+impl<'a> Extend<&'a str> for Utf16String {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|s| self.vec.extend(s.encode_utf16()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn surrogate_boundary() {
+        let s = Utf16String::from("a😀b");
+        assert_eq!(s.len(), 4);
+        assert_eq!(&s[..1], "a");
+        assert_eq!(&s[1..3], "😀");
+        assert_eq!(&s[3..], "b");
+    }
+
+    #[test]
+    #[should_panic = "index 2 is not on a UTF-16 char boundary"]
+    fn split_surrogate() {
+        let s = Utf16String::from("a😀b");
+        let _ = &s[2..];
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut s = Utf16String::new();
+        s.push('a');
+        s.push('😀');
+        assert_eq!(s.as_units(), [0x61, 0xD83D, 0xDE00]);
+        assert_eq!(s.pop(), Some('😀'));
+        assert_eq!(s.pop(), Some('a'));
+        assert_eq!(s.pop(), None);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut s = Utf16String::from("ab");
+        s.insert(1, '😀');
+        assert_eq!(s, "a😀b");
+        assert_eq!(s.remove(1), '😀');
+        assert_eq!(s.remove(1), 'b');
+        assert_eq!(s, "a");
+    }
+
+    #[test]
+    fn drain_retain() {
+        let mut s = Utf16String::from("a😀b😂c");
+        let drained: String = s.drain(1..4).collect();
+        assert_eq!(drained, "😀b");
+        assert_eq!(s, "a😂c");
+
+        s.retain(|ch| ch != '😂');
+        assert_eq!(s, "ac");
+    }
+
+    #[test]
+    fn unpaired_surrogate() {
+        assert!(Utf16String::from_units([0xDE00, 0x61].into()).is_err());
+        let s = Utf16String::from_units_lossy(&[0xDE00, 0x61]);
+        assert_eq!(s, "\u{FFFD}a");
+    }
+
+    #[test]
+    fn debug_fmt() {
+        let s = Utf16String::from("a\"😀'\n");
+        assert_eq!(alloc::format!("{s:?}"), alloc::format!("{:?}", "a\"😀'\n"));
+        assert_eq!(alloc::format!("{s}"), "a\"😀'\n");
+    }
+}
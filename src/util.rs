@@ -1,3 +1,5 @@
+use core::ops::{Bound, Range, RangeBounds};
+
 pub(crate) fn transform_char_index(s: &str, byte_index: usize) -> usize {
     debug_assert!(s.is_char_boundary(byte_index),
                  "{s:?} <- {byte_index} is not on char boundary");
@@ -6,6 +8,23 @@ pub(crate) fn transform_char_index(s: &str, byte_index: usize) -> usize {
         .count()
 }
 
+#[track_caller]
+pub(crate) fn normalize_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1).expect("start range overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1).expect("end range overflow"),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range index starts at {start} but ends at {end}");
+    assert!(end <= len, "range end index {end} out of range for slice of length {len}");
+    Range { start, end }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
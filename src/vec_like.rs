@@ -1,10 +1,27 @@
 #![deny(unconditional_recursion)]
 
-use core::ops::RangeBounds;
+use core::{fmt, ops::RangeBounds};
 
 mod pointers_impl;
+mod offset_vec_impl;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 mod vec_impl;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 mod string_impl;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod utf16_impl;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod sentinel_impl;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod aligned_impl;
+mod array_vec_impl;
+mod slice_vec_impl;
+mod slice_impl;
 #[cfg(feature = "smallvec")]
 #[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
 mod smallvec_impl;
@@ -14,6 +31,41 @@ mod smallstr_impl;
 #[cfg(feature = "rc-vec")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rc-vec")))]
 mod rc_vec_impl;
+#[cfg(feature = "bumpalo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bumpalo")))]
+mod bumpalo_impl;
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+mod bytes_impl;
+#[cfg(feature = "compact_str")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compact_str")))]
+mod compact_str_impl;
+#[cfg(feature = "smartstring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smartstring")))]
+mod smartstring_impl;
+#[cfg(feature = "thin-vec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "thin-vec")))]
+mod thin_vec_impl;
+#[cfg(feature = "ecow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecow")))]
+mod ecow_impl;
+#[cfg(feature = "tinyvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tinyvec")))]
+mod tinyvec_impl;
+#[cfg(feature = "imbl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "imbl")))]
+mod imbl_impl;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod os_string_impl;
+#[cfg(feature = "file-vec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-vec")))]
+mod file_vec_impl;
+
+#[cfg(feature = "alloc")]
+pub use pointers_impl::hidden_clones;
+#[cfg(feature = "alloc")]
+pub(crate) use pointers_impl::{arc_make_mut, rc_make_mut};
 
 use crate::Slice;
 
@@ -21,16 +73,112 @@ macro_rules! noop {
     ($($e:expr),*) => {{ $(let _ = $e;)* }};
 }
 
-pub trait VecLike {
+/// Error of pushing into a full fixed-capacity vector, carry the rejected element
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapacityError<T> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    pub const fn new(element: T) -> Self {
+        Self { element }
+    }
+
+    pub fn element(&self) -> &T {
+        &self.element
+    }
+
+    pub fn into_element(self) -> T {
+        self.element
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CapacityError").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+impl<T> core::error::Error for CapacityError<T> {}
+
+/// Error of mutating through a shared pointer, which would clone the buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SharedError;
+
+impl fmt::Display for SharedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("buffer is shared, mutation would clone it")
+    }
+}
+
+impl core::error::Error for SharedError {}
+
+/// Reference counted pointers, the mutations clone the buffer while shared,
+/// e.g [`Rc`](alloc::rc::Rc) and [`Arc`](alloc::sync::Arc)
+pub trait SharedMut {
+    type Target;
+
+    /// Other strong pointers exist, the next mutation would clone the buffer
+    fn would_clone(&self) -> bool;
+
+    /// Mutable access without cloning, fails if [`would_clone`](SharedMut::would_clone)
+    /// or a weak pointer of [`Arc`](alloc::sync::Arc) lives
+    fn try_make_mut(&mut self) -> Result<&mut Self::Target, SharedError>;
+}
+
+/// Sequence of elements, the storage may be non-contiguous, e.g chunked trees
+pub trait SeqLike {
     type Elem;
-    type ElemRef<'a> where Self: 'a;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool;
+}
+
+/// Read only view of contiguous elements, e.g `Rc<[T]>` and `&str`
+pub trait SliceLike: SeqLike {
     type Slice: ?Sized + Slice;
-    type Collection: FromIterator<Self::Elem>;
-    type Drain<'a>: Iterator<Item = Self::Elem> where Self: 'a;
 
     fn as_slice(&self) -> &Self::Slice;
+}
 
+/// Contiguous elements can be mutated, the length cannot be changed, e.g `[T; N]`
+pub trait SliceLikeMut: SliceLike {
     fn as_mut_slice(&mut self) -> &mut Self::Slice;
+}
+
+/// Elements can be removed, e.g shrink-only buffers
+pub trait Truncatable: SeqLike {
+    type ElemRef<'a> where Self: 'a;
+
+    fn truncate(&mut self, len: usize);
+
+    fn remove(&mut self, index: usize) -> Self::Elem;
+
+    fn pop(&mut self) -> Option<Self::Elem>;
+
+    fn clear(&mut self);
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(Self::ElemRef<'_>) -> bool,;
+}
+
+/// Elements can be added, e.g append-only logs
+pub trait Growable: SeqLike {
+    /// Max count of elements, fixed-capacity backends cannot grow beyond it
+    const MAX_CAPACITY: usize = usize::MAX;
+
+    /// Owned elements of [`append`](Growable::append) and [`split_off`](SeqVecLike::split_off)
+    ///
+    /// Not bound to [`FromIterator`] since 0.4,
+    /// e.g arena, file-backed and borrowed buffers cannot be collected from nothing
+    type Collection;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection;
 
@@ -44,43 +192,60 @@ pub trait VecLike {
 
     fn shrink_to(&mut self, min_capacity: usize) { noop!(min_capacity) }
 
-    fn truncate(&mut self, len: usize);
-
     fn insert(&mut self, index: usize, element: Self::Elem);
 
-    fn remove(&mut self, index: usize) -> Self::Elem;
-
     fn push(&mut self, value: Self::Elem);
 
-    fn pop(&mut self) -> Option<Self::Elem>;
+    /// Push a value, return the error when capacity is full
+    ///
+    /// Only fixed-capacity backends may fail, others like [`push`](Growable::push)
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.push(value);
+        Ok(())
+    }
+
+    /// Insert a value, return the error when capacity is full
+    ///
+    /// Only fixed-capacity backends may fail, others like [`insert`](Growable::insert)
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.insert(index, element);
+        Ok(())
+    }
 
     fn append(&mut self, other: &mut Self::Collection);
+}
+
+/// Range of elements can be removed by iterator
+pub trait Drainable: Truncatable {
+    type Drain<'a>: Iterator<Item = Self::Elem> where Self: 'a;
 
     fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
     where R: RangeBounds<usize>,;
+}
 
-    fn clear(&mut self);
-
-    fn len(&self) -> usize;
-
-    fn is_empty(&self) -> bool;
-
+/// Full capabilities of [`Vec`](alloc::vec::Vec), the storage may be non-contiguous,
+/// e.g `imbl::Vector`
+pub trait SeqVecLike: Drainable + Growable {
+    /// Required since 0.4, for a [`FromIterator`] collection the previous default is
+    /// `self.drain(at..).collect()`
     #[must_use = "use `.truncate()` if you don't need the other half"]
-    fn split_off(&mut self, at: usize) -> Self::Collection {
-        self.drain(at..).collect()
-    }
+    fn split_off(&mut self, at: usize) -> Self::Collection;
 
     fn resize(&mut self, new_len: usize, value: Self::Elem)
     where Self::Elem: Clone,;
 
     fn resize_with<F>(&mut self, new_len: usize, f: F)
     where F: FnMut() -> Self::Elem,;
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(Self::ElemRef<'_>) -> bool,;
 }
 
-pub trait VecLikeSolid: VecLike {
+/// Contiguous [`SeqVecLike`], implemented for every [`SeqVecLike`] + [`SliceLike`]
+///
+/// Backends implement [`SeqVecLike`] and [`SliceLike`], use `VecLike` as the bound
+pub trait VecLike: SeqVecLike + SliceLike {}
+
+impl<V: ?Sized + SeqVecLike + SliceLike> VecLike for V {}
+
+pub trait VecLikeSolid: Truncatable {
     fn swap_remove(&mut self, index: usize) -> Self::Elem;
 
     fn retain_mut<F>(&mut self, f: F)
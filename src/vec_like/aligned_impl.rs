@@ -0,0 +1,161 @@
+use core::ops::RangeBounds;
+use crate::{AlignedDrain, AlignedVec};
+use super::*;
+
+impl<const ALIGN: usize> SeqLike for AlignedVec<ALIGN> {
+    type Elem = u8;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<const ALIGN: usize> SliceLike for AlignedVec<ALIGN> {
+    type Slice = [u8];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+impl<const ALIGN: usize> SliceLikeMut for AlignedVec<ALIGN> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+impl<const ALIGN: usize> Truncatable for AlignedVec<ALIGN> {
+    type ElemRef<'a> = &'a u8 where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&u8) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<const ALIGN: usize> Growable for AlignedVec<ALIGN> {
+    type Collection = AlignedVec<ALIGN>;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
+    }
+}
+
+impl<const ALIGN: usize> Drainable for AlignedVec<ALIGN> {
+    type Drain<'a> = AlignedDrain<'a, ALIGN> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<const ALIGN: usize> SeqVecLike for AlignedVec<ALIGN> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize(new_len, value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(core::iter::repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+impl<const ALIGN: usize> VecLikeSolid for AlignedVec<ALIGN> {
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        self.swap_remove(index)
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        self.retain_mut(f);
+    }
+
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        let last = self.last_mut()?;
+        if predicate(last) { self.pop() } else { None }
+    }
+}
@@ -0,0 +1,170 @@
+use core::{iter::{repeat_n, repeat_with}, ops::RangeBounds};
+use crate::{array_vec::capacity_overflow, uninit::UninitDrain, ArrayVecLike};
+use super::*;
+
+impl<T, const N: usize> SeqLike for ArrayVecLike<T, N> {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T, const N: usize> SliceLike for ArrayVecLike<T, N> {
+    type Slice = [T];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+impl<T, const N: usize> SliceLikeMut for ArrayVecLike<T, N> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+impl<T, const N: usize> Truncatable for ArrayVecLike<T, N> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<T, const N: usize> Growable for ArrayVecLike<T, N> {
+    const MAX_CAPACITY: usize = N;
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        if additional > self.remaining_capacity() {
+            capacity_overflow(N)
+        }
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        Growable::reserve(self, additional);
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.try_push(value)
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.try_insert(index, element)
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
+    }
+}
+
+impl<T, const N: usize> Drainable for ArrayVecLike<T, N> {
+    type Drain<'a> = UninitDrain<'a, T> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<T, const N: usize> SeqVecLike for ArrayVecLike<T, N> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            Growable::reserve(self, new_len-len);
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            Growable::reserve(self, new_len-len);
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+impl<T, const N: usize> VecLikeSolid for ArrayVecLike<T, N> {
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        self.swap_remove(index)
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        self.retain_mut(f);
+    }
+
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        let last = self.last_mut()?;
+        if predicate(last) { self.pop() } else { None }
+    }
+}
@@ -0,0 +1,298 @@
+use core::{iter::{repeat_n, repeat_with}, ops::RangeBounds};
+use bumpalo::collections::{string, vec, String, Vec};
+use super::*;
+
+impl<'bump, T> SeqLike for Vec<'bump, T> {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<'bump, T> SliceLike for Vec<'bump, T> {
+    type Slice = [T];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+impl<'bump, T> SliceLikeMut for Vec<'bump, T> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+impl<'bump, T> Truncatable for Vec<'bump, T> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<'bump, T> Growable for Vec<'bump, T> {
+    type Collection = Vec<'bump, T>;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
+    }
+}
+
+impl<'bump, T> Drainable for Vec<'bump, T> {
+    type Drain<'a> = vec::Drain<'a, 'bump, T> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<'bump, T> SeqVecLike for Vec<'bump, T> {
+    /// The other half is allocated in the same arena
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize(new_len, value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+impl<'bump, T> VecLikeSolid for Vec<'bump, T> {
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        self.swap_remove(index)
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        self.retain_mut(f);
+    }
+
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        self.pop_if(predicate)
+    }
+}
+
+impl SeqLike for String<'_> {
+    type Elem = char;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl SliceLike for String<'_> {
+    type Slice = str;
+
+    fn as_slice(&self) -> &Self::Slice {
+        self
+    }
+}
+
+impl SliceLikeMut for String<'_> {
+    fn as_mut_slice(&mut self) -> &mut Self::Slice {
+        self
+    }
+}
+
+impl Truncatable for String<'_> {
+    type ElemRef<'a> = char where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, idx: usize) -> Self::Elem {
+        self.remove(idx)
+    }
+
+    #[track_caller]
+    fn truncate(&mut self, new_len: usize) {
+        self.truncate(new_len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(Self::Elem) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<'bump> Growable for String<'bump> {
+    type Collection = String<'bump>;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, ch: Self::Elem) {
+        self.push(ch);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, idx: usize, ch: Self::Elem) {
+        self.insert(idx, ch);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.push_str(other);
+        other.clear();
+    }
+}
+
+impl<'bump> Drainable for String<'bump> {
+    type Drain<'a> = string::Drain<'a, 'bump> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl SeqVecLike for String<'_> {
+    /// The other half is allocated in the same arena
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use core::{iter::repeat_with, mem::take, ops::RangeBounds};
+use bytes::{buf::IntoIter, BytesMut};
+use crate::util::normalize_range;
+use super::*;
+
+impl SeqLike for BytesMut {
+    type Elem = u8;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl SliceLike for BytesMut {
+    type Slice = [u8];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+impl SliceLikeMut for BytesMut {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+impl Truncatable for BytesMut {
+    type ElemRef<'a> = &'a u8 where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        let last = *self.last()?;
+        self.truncate(self.len()-1);
+        Some(last)
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        let len = self.len();
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+        let elem = self[index];
+        self.copy_within(index+1.., index);
+        self.truncate(len-1);
+        elem
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&u8) -> bool,
+    {
+        let mut kept = 0;
+        for i in 0..self.len() {
+            let elem = self[i];
+            if f(&elem) {
+                self[kept] = elem;
+                kept += 1;
+            }
+        }
+        self.truncate(kept);
+    }
+}
+
+impl Growable for BytesMut {
+    type Collection = BytesMut;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.extend_from_slice(&[value]);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        let len = self.len();
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        self.extend_from_slice(&[element]);
+        self[index..].rotate_right(1);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    /// Without copying when `other` directly follows `self` in the same allocation
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.unsplit(take(other));
+    }
+}
+
+impl Drainable for BytesMut {
+    type Drain<'a> = IntoIter<BytesMut> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let range = normalize_range(range, len);
+        let drained = BytesMut::from(&self[range.clone()]);
+        self.copy_within(range.end.., range.start);
+        self.truncate(len - range.len());
+        drained.into_iter()
+    }
+}
+
+impl SeqVecLike for BytesMut {
+    /// Without copying, the other half shares the allocation
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize(new_len, value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bytes::BytesMut;
+    use crate::{Drainable, Growable, Truncatable};
+
+    #[test]
+    fn edit() {
+        let mut buf = BytesMut::from(&b"abcdef"[..]);
+        assert_eq!(Truncatable::remove(&mut buf, 1), b'b');
+        Growable::insert(&mut buf, 0, b'z');
+        assert_eq!(buf, b"zacdef"[..]);
+        Truncatable::retain(&mut buf, |&b| b != b'c');
+        assert_eq!(Truncatable::pop(&mut buf), Some(b'f'));
+        assert_eq!(buf, b"zade"[..]);
+    }
+
+    #[test]
+    fn drain() {
+        let mut buf = BytesMut::from(&b"abcdef"[..]);
+        let drained: Vec<u8> = Drainable::drain(&mut buf, 1..4).collect();
+        assert_eq!(drained, b"bcd");
+        assert_eq!(buf, b"aef"[..]);
+    }
+}
@@ -0,0 +1,321 @@
+use core::{iter::{repeat_n, repeat_with}, ops::RangeBounds};
+use alloc::vec::{self, Vec};
+use ecow::{vec::Drain, EcoString, EcoVec};
+use crate::util::normalize_range;
+use super::*;
+
+impl<T: Clone> SeqLike for EcoVec<T> {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T: Clone> SliceLike for EcoVec<T> {
+    type Slice = [T];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+impl<T: Clone> SliceLikeMut for EcoVec<T> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self.make_mut()
+    }
+}
+
+impl<T: Clone> Truncatable for EcoVec<T> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.retain(|elem| f(elem));
+    }
+}
+
+impl<T: Clone> Growable for EcoVec<T> {
+    type Collection = EcoVec<T>;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.extend(other.drain(..));
+    }
+}
+
+impl<T: Clone> Drainable for EcoVec<T> {
+    type Drain<'a> = Drain<'a, T> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<T: Clone> SeqVecLike for EcoVec<T> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        let len = self.len();
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        let other = EcoVec::from(&self[at..]);
+        self.truncate(at);
+        other
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+impl<T: Clone> VecLikeSolid for EcoVec<T> {
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        let len = self.len();
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+        self.make_mut().swap(index, len-1);
+        self.pop().unwrap()
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        self.retain(f);
+    }
+
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        let last = self.make_mut().last_mut()?;
+        if predicate(last) { self.pop() } else { None }
+    }
+}
+
+impl SeqLike for EcoString {
+    type Elem = char;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl SliceLike for EcoString {
+    type Slice = str;
+
+    fn as_slice(&self) -> &Self::Slice {
+        self
+    }
+}
+
+impl SliceLikeMut for EcoString {
+    fn as_mut_slice(&mut self) -> &mut Self::Slice {
+        self.make_mut()
+    }
+}
+
+impl Truncatable for EcoString {
+    type ElemRef<'a> = char where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, idx: usize) -> Self::Elem {
+        self.remove(idx)
+    }
+
+    #[track_caller]
+    fn truncate(&mut self, new_len: usize) {
+        self.truncate(new_len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    /// Clone only when some char is removed
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(Self::Elem) -> bool,
+    {
+        let mut chars = self.char_indices();
+        let Some(i) = chars.find_map(|(i, ch)| (!f(ch)).then_some(i)) else {
+            return;
+        };
+        let mut kept = EcoString::from(&self[..i]);
+        kept.extend(chars.map(|(_, ch)| ch).filter(|&ch| f(ch)));
+        *self = kept;
+    }
+}
+
+impl Growable for EcoString {
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, ch: Self::Elem) {
+        self.push(ch);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, idx: usize, ch: Self::Elem) {
+        self.insert(idx, ch);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.push_str(other);
+        other.clear();
+    }
+}
+
+impl Drainable for EcoString {
+    type Drain<'a> = vec::IntoIter<char> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let range = normalize_range(range, self.len());
+        let drained: Vec<char> = self[range.clone()].chars().collect();
+        if !range.is_empty() {
+            let tail = EcoString::from(&self[range.end..]);
+            self.truncate(range.start);
+            self.push_str(&tail);
+        }
+        drained.into_iter()
+    }
+}
+
+impl SeqVecLike for EcoString {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        let other = EcoString::from(&self[at..]);
+        self.truncate(at);
+        other
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
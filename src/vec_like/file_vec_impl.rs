@@ -0,0 +1,472 @@
+use core::{iter::repeat_with, ops::RangeBounds};
+use std::{vec, vec::IntoIter};
+#[cfg(feature = "memmap2")]
+use crate::MmapVec;
+use crate::{file_vec::{chunk_buf, io_failed, zeroed}, util::normalize_range, FileVec, Pod};
+use super::*;
+
+impl<T: Pod> SeqLike for FileVec<T> {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T: Pod> Truncatable for FileVec<T> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    #[track_caller]
+    fn pop(&mut self) -> Option<Self::Elem> {
+        let last = self.len().checked_sub(1)?;
+        let elem = self.get(last).unwrap_or_else(|e| io_failed(e));
+        self.set_len(last);
+        elem
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        let len = self.len();
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+        let mut elem = [zeroed()];
+        self.read_raw(index, &mut elem)
+            .and_then(|()| self.copy_within(index+1..len, index))
+            .unwrap_or_else(|e| io_failed(e));
+        self.set_len(len-1);
+        elem[0]
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.set_len(len.min(self.len()));
+    }
+
+    fn clear(&mut self) {
+        self.set_len(0);
+    }
+
+    #[track_caller]
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.try_retain_mut(|elem| f(elem)).unwrap_or_else(|e| io_failed(e));
+    }
+}
+
+impl<T: Pod> Growable for FileVec<T> {
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).unwrap_or_else(|e| io_failed(e));
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.try_reserve_exact(additional).unwrap_or_else(|e| io_failed(e));
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.try_shrink_to_fit().unwrap_or_else(|e| io_failed(e));
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        if min_capacity < self.capacity() {
+            self.set_capacity(min_capacity.max(self.len()))
+                .unwrap_or_else(|e| io_failed(e));
+        }
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.extend_from_slice(&[value]).unwrap_or_else(|e| io_failed(e));
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        let len = self.len();
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        self.try_reserve(1)
+            .and_then(|()| self.copy_within(index..len, index+1))
+            .and_then(|()| self.write_raw(index, &[element]))
+            .unwrap_or_else(|e| io_failed(e));
+        self.set_len(len+1);
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        let mut buf = chunk_buf::<T>();
+        let mut i = 0;
+        while i < other.len() {
+            let n = buf.len().min(other.len() - i);
+            other.read_raw(i, &mut buf[..n])
+                .and_then(|()| self.extend_from_slice(&buf[..n]))
+                .unwrap_or_else(|e| io_failed(e));
+            i += n;
+        }
+        other.clear();
+    }
+}
+
+impl<T: Pod> Drainable for FileVec<T> {
+    type Drain<'a> = IntoIter<T> where Self: 'a;
+
+    /// Read the range into memory and remove it eagerly
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let range = normalize_range(range, len);
+        let mut elems = vec![zeroed(); range.len()];
+        self.read_raw(range.start, &mut elems)
+            .and_then(|()| self.copy_within(range.end..len, range.start))
+            .unwrap_or_else(|e| io_failed(e));
+        self.set_len(len - range.len());
+        elems.into_iter()
+    }
+}
+
+impl<T: Pod> SeqVecLike for FileVec<T> {
+    /// The other half is stored in an anonymous temporary file
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        let len = self.len();
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        let mut other = FileVec::temp().unwrap_or_else(|e| io_failed(e));
+        other.try_reserve_exact(len - at).unwrap_or_else(|e| io_failed(e));
+
+        let mut buf = chunk_buf::<T>();
+        let mut i = at;
+        while i < len {
+            let n = buf.len().min(len - i);
+            self.read_raw(i, &mut buf[..n])
+                .and_then(|()| other.extend_from_slice(&buf[..n]))
+                .unwrap_or_else(|e| io_failed(e));
+            i += n;
+        }
+        self.set_len(at);
+        other
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize_with(new_len, || value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.try_reserve_exact(new_len-len).unwrap_or_else(|e| io_failed(e));
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.set_len(new_len);
+        }
+    }
+}
+
+impl<T: Pod> VecLikeSolid for FileVec<T> {
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        let len = self.len();
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+        let mut elems = [zeroed(); 2];
+        self.read_raw(index, &mut elems[..1])
+            .and_then(|()| self.read_raw(len-1, &mut elems[1..]))
+            .and_then(|()| self.write_raw(index, &elems[1..]))
+            .unwrap_or_else(|e| io_failed(e));
+        self.set_len(len-1);
+        elems[0]
+    }
+
+    #[track_caller]
+    fn retain_mut<F>(&mut self, f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        self.try_retain_mut(f).unwrap_or_else(|e| io_failed(e));
+    }
+
+    /// The last element is written back when it's modified but not popped
+    #[track_caller]
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        let last = self.len().checked_sub(1)?;
+        let mut elem = self.get(last).unwrap_or_else(|e| io_failed(e))?;
+        if predicate(&mut elem) {
+            self.set_len(last);
+            return Some(elem);
+        }
+        self.write_raw(last, &[elem]).unwrap_or_else(|e| io_failed(e));
+        None
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: Pod> SeqLike for MmapVec<T> {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: Pod> SliceLike for MmapVec<T> {
+    type Slice = [T];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: Pod> SliceLikeMut for MmapVec<T> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: Pod> Truncatable for MmapVec<T> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        let last = *self.last()?;
+        self.set_len(self.len()-1);
+        Some(last)
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        let len = self.len();
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+        let elem = self[index];
+        self.copy_within(index+1.., index);
+        self.set_len(len-1);
+        elem
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.set_len(len.min(self.len()));
+    }
+
+    fn clear(&mut self) {
+        self.set_len(0);
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|elem| f(elem));
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: Pod> Growable for MmapVec<T> {
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).unwrap_or_else(|e| io_failed(e));
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.try_reserve_exact(additional).unwrap_or_else(|e| io_failed(e));
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.try_shrink_to_fit().unwrap_or_else(|e| io_failed(e));
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        if min_capacity < self.capacity() {
+            self.set_capacity(min_capacity.max(self.len()))
+                .unwrap_or_else(|e| io_failed(e));
+        }
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.extend_from_slice(&[value]).unwrap_or_else(|e| io_failed(e));
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        let len = self.len();
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        Growable::push(self, element);
+        self[index..].rotate_right(1);
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.extend_from_slice(other).unwrap_or_else(|e| io_failed(e));
+        other.clear();
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: Pod> Drainable for MmapVec<T> {
+    type Drain<'a> = IntoIter<T> where Self: 'a;
+
+    /// Copy the range into memory and remove it eagerly
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let range = normalize_range(range, len);
+        let elems = self[range.clone()].to_vec();
+        self.copy_within(range.end.., range.start);
+        self.set_len(len - range.len());
+        elems.into_iter()
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: Pod> SeqVecLike for MmapVec<T> {
+    /// The other half is mapped from an anonymous temporary file
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        let len = self.len();
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        let mut other = MmapVec::temp().unwrap_or_else(|e| io_failed(e));
+        other.extend_from_slice(&self[at..]).unwrap_or_else(|e| io_failed(e));
+        self.set_len(at);
+        other
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize_with(new_len, || value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.try_reserve_exact(new_len-len).unwrap_or_else(|e| io_failed(e));
+            self.set_len(new_len);
+            self[len..].fill_with(f);
+        } else {
+            self.set_len(new_len);
+        }
+    }
+}
+
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+impl<T: Pod> VecLikeSolid for MmapVec<T> {
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        let len = self.len();
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+        let elem = self[index];
+        self[index] = self[len-1];
+        self.set_len(len-1);
+        elem
+    }
+
+    fn retain_mut<F>(&mut self, mut f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        /// Shift down the unprocessed elements, when `f` panics
+        struct Guard<'a, T: Pod> {
+            vec: &'a mut MmapVec<T>,
+            read: usize,
+            write: usize,
+        }
+        impl<T: Pod> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                let len = self.vec.len();
+                self.vec.copy_within(self.read..len, self.write);
+                self.vec.set_len(self.write + (len - self.read));
+            }
+        }
+
+        let len = self.len();
+        let mut guard = Guard { vec: self, read: 0, write: 0 };
+
+        while guard.read < len {
+            let read = guard.read;
+            let keep = f(&mut guard.vec[read]);
+            guard.read += 1;
+            if keep {
+                guard.vec[guard.write] = guard.vec[read];
+                guard.write += 1;
+            }
+        }
+    }
+
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        let last = self.last_mut()?;
+        if predicate(last) { Truncatable::pop(self) } else { None }
+    }
+}
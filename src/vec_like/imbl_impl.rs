@@ -0,0 +1,143 @@
+use core::{iter::{repeat_n, repeat_with}, mem::take, ops::RangeBounds};
+use imbl::{shared_ptr::SharedPointerKind, vector::ConsumingIter, GenericVector};
+use crate::util::normalize_range;
+use super::*;
+
+impl<T: Clone, P: SharedPointerKind> SeqLike for GenericVector<T, P> {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T: Clone, P: SharedPointerKind> Truncatable for GenericVector<T, P> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop_back()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<T: Clone, P: SharedPointerKind> Growable for GenericVector<T, P> {
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    /// Chunks are allocated on demand, always equal to `len`
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push_back(value);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    /// Concatenate the trees, no elements are copied
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(take(other));
+    }
+}
+
+impl<T: Clone, P: SharedPointerKind> Drainable for GenericVector<T, P> {
+    type Drain<'a> = ConsumingIter<T, P> where Self: 'a;
+
+    /// Cut out the range eagerly, like [`GenericVector::slice`]
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let range = normalize_range(range, self.len());
+        self.slice(range).into_iter()
+    }
+}
+
+impl<T: Clone, P: SharedPointerKind> SeqVecLike for GenericVector<T, P> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use imbl::vector;
+    use crate::{Drainable, Truncatable};
+
+    #[test]
+    fn drain_middle() {
+        let mut vec = vector![0, 1, 2, 3, 4];
+        let drained: Vec<_> = Drainable::drain(&mut vec, 1..3).collect();
+        assert_eq!(drained, [1, 2]);
+        assert_eq!(vec, vector![0, 3, 4]);
+
+        Truncatable::truncate(&mut vec, 5);
+        assert_eq!(vec, vector![0, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn drain_out_of_bounds() {
+        let mut vec = vector![0, 1, 2];
+        Drainable::drain(&mut vec, 2..4);
+    }
+}
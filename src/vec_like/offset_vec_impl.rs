@@ -0,0 +1,169 @@
+use core::ops::RangeBounds;
+use crate::{OffsetVec, SliceMut};
+use super::*;
+
+impl<V: SeqLike> SeqLike for OffsetVec<V> {
+    type Elem = V::Elem;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<V: SliceLike> SliceLike for OffsetVec<V> {
+    type Slice = V::Slice;
+
+    fn as_slice(&self) -> &Self::Slice {
+        self.as_slice()
+    }
+}
+
+impl<V: SliceLikeMut> SliceLikeMut for OffsetVec<V> where V::Slice: SliceMut {
+    fn as_mut_slice(&mut self) -> &mut Self::Slice {
+        self.as_mut_slice()
+    }
+}
+
+/// Retain needs [`SliceLike`] to map the offset into elements, e.g chars of [`str`]
+impl<V: Truncatable + SliceLike> Truncatable for OffsetVec<V> {
+    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(V::ElemRef<'_>) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<V: Growable> Growable for OffsetVec<V> {
+    type Collection = V::Collection;
+
+    const MAX_CAPACITY: usize = V::MAX_CAPACITY;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self.vec.as_mut_collection()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    #[track_caller]
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.try_push(value)
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.try_insert(index, element)
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
+    }
+}
+
+impl<V: Drainable + SliceLike> Drainable for OffsetVec<V> {
+    type Drain<'a> = V::Drain<'a> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<V: VecLike> SeqVecLike for OffsetVec<V> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize(new_len, value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        self.resize_with(new_len, f);
+    }
+}
+
+impl<V: VecLikeSolid + SliceLike> VecLikeSolid for OffsetVec<V> {
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        self.swap_remove(index)
+    }
+
+    fn retain_mut<F>(&mut self, f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        self.retain_mut(f);
+    }
+
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        self.pop_if(predicate)
+    }
+}
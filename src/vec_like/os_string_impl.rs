@@ -0,0 +1,181 @@
+use core::{iter::{repeat_n, repeat_with}, ops::RangeBounds};
+use std::{ffi::{OsStr, OsString}, vec::{IntoIter, Vec}};
+use crate::{os_str::{self, OsSlice}, util::normalize_range};
+use super::*;
+
+impl SeqLike for OsString {
+    type Elem = OsString;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_os_str().len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl SliceLike for OsString {
+    type Slice = OsSlice;
+
+    fn as_slice(&self) -> &Self::Slice {
+        OsSlice::new(self)
+    }
+}
+
+impl SliceLikeMut for OsString {
+    fn as_mut_slice(&mut self) -> &mut Self::Slice {
+        OsSlice::new_mut(self)
+    }
+}
+
+impl Truncatable for OsString {
+    type ElemRef<'a> = &'a OsStr where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        let bytes = self.as_encoded_bytes();
+        let start = bytes.len() - os_str::last_unit_len(bytes);
+        if start == bytes.len() {
+            return None;
+        }
+        Some(SeqVecLike::split_off(self, start))
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        let unit_len = os_str::unit_len(&self.as_encoded_bytes()[index..]);
+        if unit_len == 0 {
+            panic!("cannot remove a unit from the end of a OsString");
+        }
+        let range = index..index+unit_len;
+        let elem = OsSlice::new(self)[range.clone()].to_os_string();
+        os_str::replace(self, range, OsStr::new(""));
+        elem
+    }
+
+    #[track_caller]
+    fn truncate(&mut self, len: usize) {
+        os_str::truncate(self, len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&OsStr) -> bool,
+    {
+        let slice = OsSlice::new(self);
+        let mut retained = OsString::with_capacity(slice.len());
+
+        for unit in os_str::units(slice.as_encoded_bytes()) {
+            let unit = &slice[unit];
+            if f(unit) {
+                retained.push(unit);
+            }
+        }
+
+        *self = retained;
+    }
+}
+
+impl Growable for OsString {
+    type Collection = OsString;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        os_str::replace(self, index..index, &element);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.push(&*other);
+        other.clear();
+    }
+}
+
+impl Drainable for OsString {
+    type Drain<'a> = IntoIter<OsString>;
+
+    /// Units are removed eagerly, and the iterator is owned
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let range = normalize_range(range, self.len());
+        let slice = &OsSlice::new(self)[range.clone()];
+        let elems = os_str::units(slice.as_encoded_bytes())
+            .map(|unit| slice[unit].to_os_string())
+            .collect::<Vec<_>>();
+        os_str::replace(self, range, OsStr::new(""));
+        elems.into_iter()
+    }
+}
+
+impl SeqVecLike for OsString {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        let other = OsSlice::new(self)[at..].to_os_string();
+        os_str::truncate(self, at);
+        other
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            os_str::truncate(self, new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            os_str::truncate(self, new_len);
+        }
+    }
+}
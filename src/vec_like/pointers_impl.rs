@@ -1,16 +1,20 @@
+use core::cell::RefMut;
+#[cfg(feature = "alloc")]
 use alloc::{borrow::{Cow, ToOwned}, boxed::Box, rc::Rc, sync::Arc};
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
+#[cfg(feature = "std")]
+use std::sync::{MutexGuard, RwLockWriteGuard};
+#[cfg(all(feature = "alloc", debug_assertions))]
+use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 use super::*;
 
 #[cfg(feature = "unique-rc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "unique-rc")))]
 mod unique_rc_impl;
 
-impl<V: VecLike> VecLike for &mut V {
+impl<V: ?Sized + SeqLike> SeqLike for &mut V {
     type Elem = V::Elem;
-    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
-    type Slice = V::Slice;
-    type Collection = V::Collection;
-    type Drain<'a> = V::Drain<'a> where Self: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -21,14 +25,53 @@ impl<V: VecLike> VecLike for &mut V {
     fn is_empty(&self) -> bool {
         (**self).is_empty()
     }
+}
+
+impl<V: ?Sized + SliceLike> SliceLike for &mut V {
+    type Slice = V::Slice;
 
     fn as_slice(&self) -> &Self::Slice {
         (**self).as_slice()
     }
+}
 
+impl<V: ?Sized + SliceLikeMut> SliceLikeMut for &mut V {
     fn as_mut_slice(&mut self) -> &mut Self::Slice {
         (**self).as_mut_slice()
     }
+}
+
+impl<V: ?Sized + Truncatable> Truncatable for &mut V {
+    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        (**self).pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        (**self).remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        (**self).truncate(len);
+    }
+
+    fn clear(&mut self) {
+        (**self).clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(V::ElemRef<'_>) -> bool,
+    {
+        (**self).retain(f);
+    }
+}
+
+impl<V: ?Sized + Growable> Growable for &mut V {
+    type Collection = V::Collection;
+
+    const MAX_CAPACITY: usize = V::MAX_CAPACITY;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         (**self).as_mut_collection()
@@ -38,18 +81,19 @@ impl<V: VecLike> VecLike for &mut V {
         (**self).capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        (**self).pop()
-    }
-
     #[track_caller]
     fn push(&mut self, value: Self::Elem) {
         (**self).push(value);
     }
 
     #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        (**self).remove(index)
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        (**self).try_push(value)
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        (**self).try_insert(index, element)
     }
 
     #[track_caller]
@@ -77,10 +121,23 @@ impl<V: VecLike> VecLike for &mut V {
         (**self).shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        (**self).truncate(len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        (**self).append(other);
+    }
+}
+
+impl<V: ?Sized + Drainable> Drainable for &mut V {
+    type Drain<'a> = V::Drain<'a> where Self: 'a;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        (**self).drain(range)
     }
+}
 
+impl<V: ?Sized + SeqVecLike> SeqVecLike for &mut V {
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
         (**self).split_off(at)
@@ -99,29 +156,8 @@ impl<V: VecLike> VecLike for &mut V {
     {
         (**self).resize_with(new_len, f);
     }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        (**self).drain(range)
-    }
-
-    fn clear(&mut self) {
-        (**self).clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        (**self).append(other);
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(V::ElemRef<'_>) -> bool,
-    {
-        (**self).retain(f);
-    }
 }
-impl<V: VecLikeSolid> VecLikeSolid for &mut V {
+impl<V: ?Sized + VecLikeSolid> VecLikeSolid for &mut V {
     fn swap_remove(&mut self, index: usize) -> Self::Elem {
         (**self).swap_remove(index)
     }
@@ -139,146 +175,466 @@ impl<V: VecLikeSolid> VecLikeSolid for &mut V {
     }
 }
 
-impl<V: VecLike> VecLike for Box<V> {
-    type Elem = V::Elem;
-    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
-    type Slice = V::Slice;
-    type Collection = V::Collection;
-    type Drain<'a> = V::Drain<'a> where Self: 'a;
+#[cfg(feature = "alloc")]
+macro_rules! impl_box {
+    ($($A:ident)?) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: ?Sized + SeqLike $(, $A: Allocator)?> SeqLike for Box<V $(, $A)?> {
+            type Elem = V::Elem;
+
+            #[inline]
+            fn len(&self) -> usize {
+                (**self).len()
+            }
+
+            #[inline]
+            fn is_empty(&self) -> bool {
+                (**self).is_empty()
+            }
+        }
 
-    #[inline]
-    fn len(&self) -> usize {
-        (**self).len()
-    }
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: ?Sized + SliceLike $(, $A: Allocator)?> SliceLike for Box<V $(, $A)?> {
+            type Slice = V::Slice;
 
-    #[inline]
-    fn is_empty(&self) -> bool {
-        (**self).is_empty()
-    }
+            fn as_slice(&self) -> &Self::Slice {
+                (**self).as_slice()
+            }
+        }
 
-    fn as_slice(&self) -> &Self::Slice {
-        (**self).as_slice()
-    }
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: ?Sized + SliceLikeMut $(, $A: Allocator)?> SliceLikeMut for Box<V $(, $A)?> {
+            fn as_mut_slice(&mut self) -> &mut Self::Slice {
+                (**self).as_mut_slice()
+            }
+        }
 
-    fn as_mut_slice(&mut self) -> &mut Self::Slice {
-        (**self).as_mut_slice()
-    }
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: ?Sized + Truncatable $(, $A: Allocator)?> Truncatable for Box<V $(, $A)?> {
+            type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
 
-    fn as_mut_collection(&mut self) -> &mut Self::Collection {
-        (**self).as_mut_collection()
-    }
+            fn pop(&mut self) -> Option<Self::Elem> {
+                (**self).pop()
+            }
 
-    fn capacity(&self) -> usize {
-        (**self).capacity()
-    }
+            #[track_caller]
+            fn remove(&mut self, index: usize) -> Self::Elem {
+                (**self).remove(index)
+            }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        (**self).pop()
-    }
+            fn truncate(&mut self, len: usize) {
+                (**self).truncate(len);
+            }
 
-    #[track_caller]
-    fn push(&mut self, value: Self::Elem) {
-        (**self).push(value);
-    }
+            fn clear(&mut self) {
+                (**self).clear();
+            }
 
-    #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        (**self).remove(index)
-    }
+            fn retain<F>(&mut self, f: F)
+            where F: FnMut(V::ElemRef<'_>) -> bool,
+            {
+                (**self).retain(f);
+            }
+        }
 
-    #[track_caller]
-    fn insert(&mut self, index: usize, element: Self::Elem) {
-        (**self).insert(index, element);
-    }
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: ?Sized + Growable $(, $A: Allocator)?> Growable for Box<V $(, $A)?> {
+            type Collection = V::Collection;
+
+            const MAX_CAPACITY: usize = V::MAX_CAPACITY;
+
+            fn as_mut_collection(&mut self) -> &mut Self::Collection {
+                (**self).as_mut_collection()
+            }
+
+            fn capacity(&self) -> usize {
+                (**self).capacity()
+            }
+
+            #[track_caller]
+            fn push(&mut self, value: Self::Elem) {
+                (**self).push(value);
+            }
+
+            #[track_caller]
+            fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+                (**self).try_push(value)
+            }
+
+            #[track_caller]
+            fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+                (**self).try_insert(index, element)
+            }
+
+            #[track_caller]
+            fn insert(&mut self, index: usize, element: Self::Elem) {
+                (**self).insert(index, element);
+            }
+
+            #[track_caller]
+            fn reserve(&mut self, additional: usize) {
+                (**self).reserve(additional)
+            }
+
+            #[track_caller]
+            fn reserve_exact(&mut self, additional: usize) {
+                (**self).reserve_exact(additional)
+            }
+
+            #[track_caller]
+            fn shrink_to(&mut self, min_capacity: usize) {
+                (**self).shrink_to(min_capacity)
+            }
+
+            #[track_caller]
+            fn shrink_to_fit(&mut self) {
+                (**self).shrink_to_fit()
+            }
+
+            #[track_caller]
+            fn append(&mut self, other: &mut Self::Collection) {
+                (**self).append(other);
+            }
+        }
 
-    #[track_caller]
-    fn reserve(&mut self, additional: usize) {
-        (**self).reserve(additional)
-    }
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: ?Sized + Drainable $(, $A: Allocator)?> Drainable for Box<V $(, $A)?> {
+            type Drain<'a> = V::Drain<'a> where Self: 'a;
 
-    #[track_caller]
-    fn reserve_exact(&mut self, additional: usize) {
-        (**self).reserve_exact(additional)
-    }
+            fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+            where R: RangeBounds<usize>,
+            {
+                (**self).drain(range)
+            }
+        }
 
-    #[track_caller]
-    fn shrink_to(&mut self, min_capacity: usize) {
-        (**self).shrink_to(min_capacity)
-    }
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: ?Sized + SeqVecLike $(, $A: Allocator)?> SeqVecLike for Box<V $(, $A)?> {
+            #[track_caller]
+            fn split_off(&mut self, at: usize) -> Self::Collection {
+                (**self).split_off(at)
+            }
+
+            #[track_caller]
+            fn resize(&mut self, new_len: usize, value: Self::Elem)
+            where Self::Elem: Clone,
+            {
+                (**self).resize(new_len, value);
+            }
+
+            #[track_caller]
+            fn resize_with<F>(&mut self, new_len: usize, f: F)
+            where F: FnMut() -> Self::Elem,
+            {
+                (**self).resize_with(new_len, f);
+            }
+        }
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<V: ?Sized + VecLikeSolid $(, $A: Allocator)?> VecLikeSolid for Box<V $(, $A)?> {
+            fn swap_remove(&mut self, index: usize) -> Self::Elem {
+                (**self).swap_remove(index)
+            }
+
+            fn retain_mut<F>(&mut self, f: F)
+            where F: FnMut(&mut Self::Elem) -> bool,
+            {
+                (**self).retain_mut(f);
+            }
+
+            fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+            where F: FnOnce(&mut Self::Elem) -> bool,
+            {
+                (**self).pop_if(predicate)
+            }
+        }
+    };
+}
 
-    #[track_caller]
-    fn shrink_to_fit(&mut self) {
-        (**self).shrink_to_fit()
-    }
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl_box!();
+#[cfg(feature = "allocator_api")]
+impl_box!(A);
+
+macro_rules! impl_guard {
+    ($(#[$attr:meta])* $G:ident) => {
+        $(#[$attr])*
+        impl<V: SeqLike> SeqLike for $G<'_, V> {
+            type Elem = V::Elem;
+
+            #[inline]
+            fn len(&self) -> usize {
+                (**self).len()
+            }
+
+            #[inline]
+            fn is_empty(&self) -> bool {
+                (**self).is_empty()
+            }
+        }
 
-    fn truncate(&mut self, len: usize) {
-        (**self).truncate(len);
-    }
+        $(#[$attr])*
+        impl<V: SliceLike> SliceLike for $G<'_, V> {
+            type Slice = V::Slice;
 
-    #[track_caller]
-    fn split_off(&mut self, at: usize) -> Self::Collection {
-        (**self).split_off(at)
-    }
+            fn as_slice(&self) -> &Self::Slice {
+                (**self).as_slice()
+            }
+        }
 
-    #[track_caller]
-    fn resize(&mut self, new_len: usize, value: Self::Elem)
-    where Self::Elem: Clone,
-    {
-        (**self).resize(new_len, value);
-    }
+        $(#[$attr])*
+        impl<V: SliceLikeMut> SliceLikeMut for $G<'_, V> {
+            fn as_mut_slice(&mut self) -> &mut Self::Slice {
+                (**self).as_mut_slice()
+            }
+        }
 
-    #[track_caller]
-    fn resize_with<F>(&mut self, new_len: usize, f: F)
-    where F: FnMut() -> Self::Elem,
-    {
-        (**self).resize_with(new_len, f);
+        $(#[$attr])*
+        impl<V: Truncatable> Truncatable for $G<'_, V> {
+            type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
+
+            fn pop(&mut self) -> Option<Self::Elem> {
+                (**self).pop()
+            }
+
+            #[track_caller]
+            fn remove(&mut self, index: usize) -> Self::Elem {
+                (**self).remove(index)
+            }
+
+            fn truncate(&mut self, len: usize) {
+                (**self).truncate(len);
+            }
+
+            fn clear(&mut self) {
+                (**self).clear();
+            }
+
+            fn retain<F>(&mut self, f: F)
+            where F: FnMut(V::ElemRef<'_>) -> bool,
+            {
+                (**self).retain(f);
+            }
+        }
+
+        $(#[$attr])*
+        impl<V: Growable> Growable for $G<'_, V> {
+            type Collection = V::Collection;
+
+            const MAX_CAPACITY: usize = V::MAX_CAPACITY;
+
+            fn as_mut_collection(&mut self) -> &mut Self::Collection {
+                (**self).as_mut_collection()
+            }
+
+            fn capacity(&self) -> usize {
+                (**self).capacity()
+            }
+
+            #[track_caller]
+            fn push(&mut self, value: Self::Elem) {
+                (**self).push(value);
+            }
+
+            #[track_caller]
+            fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+                (**self).try_push(value)
+            }
+
+            #[track_caller]
+            fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+                (**self).try_insert(index, element)
+            }
+
+            #[track_caller]
+            fn insert(&mut self, index: usize, element: Self::Elem) {
+                (**self).insert(index, element);
+            }
+
+            #[track_caller]
+            fn reserve(&mut self, additional: usize) {
+                (**self).reserve(additional)
+            }
+
+            #[track_caller]
+            fn reserve_exact(&mut self, additional: usize) {
+                (**self).reserve_exact(additional)
+            }
+
+            #[track_caller]
+            fn shrink_to(&mut self, min_capacity: usize) {
+                (**self).shrink_to(min_capacity)
+            }
+
+            #[track_caller]
+            fn shrink_to_fit(&mut self) {
+                (**self).shrink_to_fit()
+            }
+
+            #[track_caller]
+            fn append(&mut self, other: &mut Self::Collection) {
+                (**self).append(other);
+            }
+        }
+
+        $(#[$attr])*
+        impl<V: Drainable> Drainable for $G<'_, V> {
+            type Drain<'a> = V::Drain<'a> where Self: 'a;
+
+            fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+            where R: RangeBounds<usize>,
+            {
+                (**self).drain(range)
+            }
+        }
+
+        $(#[$attr])*
+        impl<V: SeqVecLike> SeqVecLike for $G<'_, V> {
+            #[track_caller]
+            fn split_off(&mut self, at: usize) -> Self::Collection {
+                (**self).split_off(at)
+            }
+
+            #[track_caller]
+            fn resize(&mut self, new_len: usize, value: Self::Elem)
+            where Self::Elem: Clone,
+            {
+                (**self).resize(new_len, value);
+            }
+
+            #[track_caller]
+            fn resize_with<F>(&mut self, new_len: usize, f: F)
+            where F: FnMut() -> Self::Elem,
+            {
+                (**self).resize_with(new_len, f);
+            }
+        }
+        $(#[$attr])*
+        impl<V: VecLikeSolid> VecLikeSolid for $G<'_, V> {
+            fn swap_remove(&mut self, index: usize) -> Self::Elem {
+                (**self).swap_remove(index)
+            }
+
+            fn retain_mut<F>(&mut self, f: F)
+            where F: FnMut(&mut Self::Elem) -> bool,
+            {
+                (**self).retain_mut(f);
+            }
+
+            fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+            where F: FnOnce(&mut Self::Elem) -> bool,
+            {
+                (**self).pop_if(predicate)
+            }
+        }
+    };
+}
+
+impl_guard!(RefMut);
+impl_guard!(
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    MutexGuard
+);
+impl_guard!(
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    RwLockWriteGuard
+);
+
+#[cfg(all(feature = "alloc", debug_assertions))]
+static HIDDEN_CLONES: AtomicUsize = AtomicUsize::new(0);
+
+/// Count of buffers cloned by mutating through a shared [`Rc`] or [`Arc`],
+/// including [`Offset::offset_mut`](crate::Offset::offset_mut)
+///
+/// Only counted with `debug_assertions`, otherwise always 0
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn hidden_clones() -> usize {
+    #[cfg(debug_assertions)]
+    return HIDDEN_CLONES.load(Relaxed);
+    #[cfg(not(debug_assertions))]
+    return 0;
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+fn count_clone(shared: bool) {
+    #[cfg(debug_assertions)]
+    if shared {
+        HIDDEN_CLONES.fetch_add(1, Relaxed);
     }
+    #[cfg(not(debug_assertions))]
+    let _ = shared;
+}
 
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        (**self).drain(range)
+#[cfg(feature = "alloc")]
+pub(crate) fn rc_make_mut<V: Clone>(this: &mut Rc<V>) -> &mut V {
+    count_clone(this.would_clone());
+    Rc::make_mut(this)
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn arc_make_mut<V: Clone>(this: &mut Arc<V>) -> &mut V {
+    count_clone(this.would_clone());
+    Arc::make_mut(this)
+}
+
+/// Only weak pointers do not count, [`Rc::make_mut`] moves out of them without cloning
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Clone> SharedMut for Rc<V> {
+    type Target = V;
+
+    #[inline]
+    fn would_clone(&self) -> bool {
+        Rc::strong_count(self) != 1
     }
 
-    fn clear(&mut self) {
-        (**self).clear();
+    fn try_make_mut(&mut self) -> Result<&mut V, SharedError> {
+        if self.would_clone() {
+            return Err(SharedError);
+        }
+        Ok(Rc::make_mut(self))
     }
+}
 
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        (**self).append(other);
+/// Only weak pointers do not count, [`Arc::make_mut`] moves out of them without cloning
+///
+/// But [`try_make_mut`](SharedMut::try_make_mut) also fails while a [`Weak`](alloc::sync::Weak) lives,
+/// since [`Arc::get_mut`] is the only check that another thread cannot race with [`upgrade`](alloc::sync::Weak::upgrade)
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Clone> SharedMut for Arc<V> {
+    type Target = V;
+
+    #[inline]
+    fn would_clone(&self) -> bool {
+        Arc::strong_count(self) != 1
     }
 
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(V::ElemRef<'_>) -> bool,
-    {
-        (**self).retain(f);
+    fn try_make_mut(&mut self) -> Result<&mut V, SharedError> {
+        Arc::get_mut(self).ok_or(SharedError)
     }
 }
-impl<V: VecLikeSolid> VecLikeSolid for Box<V> {
-    fn swap_remove(&mut self, index: usize) -> Self::Elem {
-        (**self).swap_remove(index)
-    }
 
-    fn retain_mut<F>(&mut self, f: F)
-    where F: FnMut(&mut Self::Elem) -> bool,
-    {
-        (**self).retain_mut(f);
+impl<P: SharedMut + ?Sized> SharedMut for &mut P {
+    type Target = P::Target;
+
+    #[inline]
+    fn would_clone(&self) -> bool {
+        (**self).would_clone()
     }
 
-    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
-    where F: FnOnce(&mut Self::Elem) -> bool,
-    {
-        (**self).pop_if(predicate)
+    fn try_make_mut(&mut self) -> Result<&mut Self::Target, SharedError> {
+        (**self).try_make_mut()
     }
 }
 
-impl<V: VecLike + Clone> VecLike for Rc<V> {
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: ?Sized + SeqLike> SeqLike for Rc<V> {
     type Elem = V::Elem;
-    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
-    type Slice = V::Slice;
-    type Collection = V::Collection;
-    type Drain<'a> = V::Drain<'a> where Self: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -289,130 +645,174 @@ impl<V: VecLike + Clone> VecLike for Rc<V> {
     fn is_empty(&self) -> bool {
         (**self).is_empty()
     }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: ?Sized + SliceLike> SliceLike for Rc<V> {
+    type Slice = V::Slice;
 
     fn as_slice(&self) -> &Self::Slice {
         (**self).as_slice()
     }
+}
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: SliceLikeMut + Clone> SliceLikeMut for Rc<V> {
     fn as_mut_slice(&mut self) -> &mut Self::Slice {
-        Self::make_mut(self).as_mut_slice()
+        rc_make_mut(self).as_mut_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Truncatable + Clone> Truncatable for Rc<V> {
+    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        rc_make_mut(self).pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        rc_make_mut(self).remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        rc_make_mut(self).truncate(len);
+    }
+
+    fn clear(&mut self) {
+        rc_make_mut(self).clear();
     }
 
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(V::ElemRef<'_>) -> bool,
+    {
+        rc_make_mut(self).retain(f);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Growable + Clone> Growable for Rc<V> {
+    type Collection = V::Collection;
+
+    const MAX_CAPACITY: usize = V::MAX_CAPACITY;
+
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
-        Self::make_mut(self).as_mut_collection()
+        rc_make_mut(self).as_mut_collection()
     }
 
     fn capacity(&self) -> usize {
         (**self).capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        Self::make_mut(self).pop()
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        rc_make_mut(self).push(value);
     }
 
     #[track_caller]
-    fn push(&mut self, value: Self::Elem) {
-        Self::make_mut(self).push(value);
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        rc_make_mut(self).try_push(value)
     }
 
     #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        Self::make_mut(self).remove(index)
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        rc_make_mut(self).try_insert(index, element)
     }
 
     #[track_caller]
     fn insert(&mut self, index: usize, element: Self::Elem) {
-        Self::make_mut(self).insert(index, element);
+        rc_make_mut(self).insert(index, element);
     }
 
     #[track_caller]
     fn reserve(&mut self, additional: usize) {
-        Self::make_mut(self).reserve(additional)
+        rc_make_mut(self).reserve(additional)
     }
 
     #[track_caller]
     fn reserve_exact(&mut self, additional: usize) {
-        Self::make_mut(self).reserve_exact(additional)
+        rc_make_mut(self).reserve_exact(additional)
     }
 
     #[track_caller]
     fn shrink_to(&mut self, min_capacity: usize) {
-        Self::make_mut(self).shrink_to(min_capacity)
+        rc_make_mut(self).shrink_to(min_capacity)
     }
 
     #[track_caller]
     fn shrink_to_fit(&mut self) {
-        Self::make_mut(self).shrink_to_fit()
+        rc_make_mut(self).shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        Self::make_mut(self).truncate(len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        rc_make_mut(self).append(other);
     }
+}
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Drainable + Clone> Drainable for Rc<V> {
+    type Drain<'a> = V::Drain<'a> where Self: 'a;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        rc_make_mut(self).drain(range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: SeqVecLike + Clone> SeqVecLike for Rc<V> {
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
-        Self::make_mut(self).split_off(at)
+        rc_make_mut(self).split_off(at)
     }
 
     #[track_caller]
     fn resize(&mut self, new_len: usize, value: Self::Elem)
     where Self::Elem: Clone,
     {
-        Self::make_mut(self).resize(new_len, value);
+        rc_make_mut(self).resize(new_len, value);
     }
 
     #[track_caller]
     fn resize_with<F>(&mut self, new_len: usize, f: F)
     where F: FnMut() -> Self::Elem,
     {
-        Self::make_mut(self).resize_with(new_len, f);
-    }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        Self::make_mut(self).drain(range)
-    }
-
-    fn clear(&mut self) {
-        Self::make_mut(self).clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        Self::make_mut(self).append(other);
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(V::ElemRef<'_>) -> bool,
-    {
-        Self::make_mut(self).retain(f);
+        rc_make_mut(self).resize_with(new_len, f);
     }
 }
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<V: VecLikeSolid + Clone> VecLikeSolid for Rc<V> {
     fn swap_remove(&mut self, index: usize) -> Self::Elem {
-        Self::make_mut(self).swap_remove(index)
+        rc_make_mut(self).swap_remove(index)
     }
 
     fn retain_mut<F>(&mut self, f: F)
     where F: FnMut(&mut Self::Elem) -> bool,
     {
-        Self::make_mut(self).retain_mut(f);
+        rc_make_mut(self).retain_mut(f);
     }
 
     fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
     where F: FnOnce(&mut Self::Elem) -> bool,
     {
-        Self::make_mut(self).pop_if(predicate)
+        rc_make_mut(self).pop_if(predicate)
     }
 }
 
-impl<V: VecLike + Clone> VecLike for Arc<V> {
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: ?Sized + SeqLike> SeqLike for Arc<V> {
     type Elem = V::Elem;
-    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
-    type Slice = V::Slice;
-    type Collection = V::Collection;
-    type Drain<'a> = V::Drain<'a> where Self: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -423,133 +823,177 @@ impl<V: VecLike + Clone> VecLike for Arc<V> {
     fn is_empty(&self) -> bool {
         (**self).is_empty()
     }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: ?Sized + SliceLike> SliceLike for Arc<V> {
+    type Slice = V::Slice;
 
     fn as_slice(&self) -> &Self::Slice {
         (**self).as_slice()
     }
+}
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: SliceLikeMut + Clone> SliceLikeMut for Arc<V> {
     fn as_mut_slice(&mut self) -> &mut Self::Slice {
-        Self::make_mut(self).as_mut_slice()
+        arc_make_mut(self).as_mut_slice()
     }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Truncatable + Clone> Truncatable for Arc<V> {
+    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        arc_make_mut(self).pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        arc_make_mut(self).remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        arc_make_mut(self).truncate(len);
+    }
+
+    fn clear(&mut self) {
+        arc_make_mut(self).clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(V::ElemRef<'_>) -> bool,
+    {
+        arc_make_mut(self).retain(f);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Growable + Clone> Growable for Arc<V> {
+    type Collection = V::Collection;
+
+    const MAX_CAPACITY: usize = V::MAX_CAPACITY;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
-        Self::make_mut(self).as_mut_collection()
+        arc_make_mut(self).as_mut_collection()
     }
 
     fn capacity(&self) -> usize {
         (**self).capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        Self::make_mut(self).pop()
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        arc_make_mut(self).push(value);
     }
 
     #[track_caller]
-    fn push(&mut self, value: Self::Elem) {
-        Self::make_mut(self).push(value);
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        arc_make_mut(self).try_push(value)
     }
 
     #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        Self::make_mut(self).remove(index)
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        arc_make_mut(self).try_insert(index, element)
     }
 
     #[track_caller]
     fn insert(&mut self, index: usize, element: Self::Elem) {
-        Self::make_mut(self).insert(index, element);
+        arc_make_mut(self).insert(index, element);
     }
 
     #[track_caller]
     fn reserve(&mut self, additional: usize) {
-        Self::make_mut(self).reserve(additional)
+        arc_make_mut(self).reserve(additional)
     }
 
     #[track_caller]
     fn reserve_exact(&mut self, additional: usize) {
-        Self::make_mut(self).reserve_exact(additional)
+        arc_make_mut(self).reserve_exact(additional)
     }
 
     #[track_caller]
     fn shrink_to(&mut self, min_capacity: usize) {
-        Self::make_mut(self).shrink_to(min_capacity)
+        arc_make_mut(self).shrink_to(min_capacity)
     }
 
     #[track_caller]
     fn shrink_to_fit(&mut self) {
-        Self::make_mut(self).shrink_to_fit()
+        arc_make_mut(self).shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        Self::make_mut(self).truncate(len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        arc_make_mut(self).append(other);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: Drainable + Clone> Drainable for Arc<V> {
+    type Drain<'a> = V::Drain<'a> where Self: 'a;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        arc_make_mut(self).drain(range)
     }
+}
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<V: SeqVecLike + Clone> SeqVecLike for Arc<V> {
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
-        Self::make_mut(self).split_off(at)
+        arc_make_mut(self).split_off(at)
     }
 
     #[track_caller]
     fn resize(&mut self, new_len: usize, value: Self::Elem)
     where Self::Elem: Clone,
     {
-        Self::make_mut(self).resize(new_len, value);
+        arc_make_mut(self).resize(new_len, value);
     }
 
     #[track_caller]
     fn resize_with<F>(&mut self, new_len: usize, f: F)
     where F: FnMut() -> Self::Elem,
     {
-        Self::make_mut(self).resize_with(new_len, f);
-    }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        Self::make_mut(self).drain(range)
-    }
-
-    fn clear(&mut self) {
-        Self::make_mut(self).clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        Self::make_mut(self).append(other);
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(V::ElemRef<'_>) -> bool,
-    {
-        Self::make_mut(self).retain(f);
+        arc_make_mut(self).resize_with(new_len, f);
     }
 }
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<V: VecLikeSolid + Clone> VecLikeSolid for Arc<V> {
     fn swap_remove(&mut self, index: usize) -> Self::Elem {
-        Self::make_mut(self).swap_remove(index)
+        arc_make_mut(self).swap_remove(index)
     }
 
     fn retain_mut<F>(&mut self, f: F)
     where F: FnMut(&mut Self::Elem) -> bool,
     {
-        Self::make_mut(self).retain_mut(f);
+        arc_make_mut(self).retain_mut(f);
     }
 
     fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
     where F: FnOnce(&mut Self::Elem) -> bool,
     {
-        Self::make_mut(self).pop_if(predicate)
+        arc_make_mut(self).pop_if(predicate)
     }
 }
 
-impl<S> VecLike for Cow<'_, S>
-where S: ToOwned + Slice,
-      S::Owned: VecLike<Slice = S>,
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<S> SeqLike for Cow<'_, S>
+where S: ?Sized + ToOwned + Slice,
+      S::Owned: SliceLike<Slice = S>,
 {
-    type Elem = <S::Owned as VecLike>::Elem;
-    type ElemRef<'a> = <S::Owned as VecLike>::ElemRef<'a> where Self: 'a;
-    type Slice = <S::Owned as VecLike>::Slice;
-    type Collection = <S::Owned as VecLike>::Collection;
-    type Drain<'a> = <S::Owned as VecLike>::Drain<'a> where Self: 'a;
+    type Elem = <S::Owned as SeqLike>::Elem;
 
     #[inline]
     fn len(&self) -> usize {
@@ -560,14 +1004,73 @@ where S: ToOwned + Slice,
     fn is_empty(&self) -> bool {
         (**self).is_empty()
     }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<S> SliceLike for Cow<'_, S>
+where S: ?Sized + ToOwned + Slice,
+      S::Owned: SliceLike<Slice = S>,
+{
+    type Slice = <S::Owned as SliceLike>::Slice;
 
     fn as_slice(&self) -> &Self::Slice {
         self
     }
+}
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<S> SliceLikeMut for Cow<'_, S>
+where S: ?Sized + ToOwned + Slice,
+      S::Owned: SliceLikeMut<Slice = S>,
+{
     fn as_mut_slice(&mut self) -> &mut Self::Slice {
         self.to_mut().as_mut_slice()
     }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<S> Truncatable for Cow<'_, S>
+where S: ?Sized + ToOwned + Slice,
+      S::Owned: Truncatable + SliceLike<Slice = S>,
+{
+    type ElemRef<'a> = <S::Owned as Truncatable>::ElemRef<'a> where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.to_mut().pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.to_mut().remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.to_mut().truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.to_mut().clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(<S::Owned as Truncatable>::ElemRef<'_>) -> bool,
+    {
+        self.to_mut().retain(f);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<S> Growable for Cow<'_, S>
+where S: ?Sized + ToOwned + Slice,
+      S::Owned: Growable + SliceLike<Slice = S>,
+{
+    type Collection = <S::Owned as Growable>::Collection;
+
+    const MAX_CAPACITY: usize = <S::Owned as Growable>::MAX_CAPACITY;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         self.to_mut().as_mut_collection()
@@ -580,18 +1083,19 @@ where S: ToOwned + Slice,
         }
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        self.to_mut().pop()
-    }
-
     #[track_caller]
     fn push(&mut self, value: Self::Elem) {
         self.to_mut().push(value);
     }
 
     #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        self.to_mut().remove(index)
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.to_mut().try_push(value)
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        self.to_mut().try_insert(index, element)
     }
 
     #[track_caller]
@@ -619,10 +1123,33 @@ where S: ToOwned + Slice,
         self.to_mut().shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        self.to_mut().truncate(len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.to_mut().append(other);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<S> Drainable for Cow<'_, S>
+where S: ?Sized + ToOwned + Slice,
+      S::Owned: Drainable + SliceLike<Slice = S>,
+{
+    type Drain<'a> = <S::Owned as Drainable>::Drain<'a> where Self: 'a;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.to_mut().drain(range)
     }
+}
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<S> SeqVecLike for Cow<'_, S>
+where S: ?Sized + ToOwned + Slice,
+      S::Owned: VecLike<Slice = S>,
+{
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
         self.to_mut().split_off(at)
@@ -641,31 +1168,12 @@ where S: ToOwned + Slice,
     {
         self.to_mut().resize_with(new_len, f);
     }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        self.to_mut().drain(range)
-    }
-
-    fn clear(&mut self) {
-        self.to_mut().clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        self.to_mut().append(other);
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(<S::Owned as VecLike>::ElemRef<'_>) -> bool,
-    {
-        self.to_mut().retain(f);
-    }
 }
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<S> VecLikeSolid for Cow<'_, S>
-where S: ToOwned + Slice,
-      S::Owned: VecLikeSolid<Slice = S>,
+where S: ?Sized + ToOwned + Slice,
+      S::Owned: VecLikeSolid + SliceLike<Slice = S>,
 {
     fn swap_remove(&mut self, index: usize) -> Self::Elem {
         self.to_mut().swap_remove(index)
@@ -1,12 +1,8 @@
 use unique_rc::{UniqArc, UniqRc};
 use super::*;
 
-impl<V: VecLike> VecLike for UniqRc<V> {
+impl<V: SeqLike> SeqLike for UniqRc<V> {
     type Elem = V::Elem;
-    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
-    type Slice = V::Slice;
-    type Collection = V::Collection;
-    type Drain<'a> = V::Drain<'a> where Self: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -17,14 +13,53 @@ impl<V: VecLike> VecLike for UniqRc<V> {
     fn is_empty(&self) -> bool {
         (**self).is_empty()
     }
+}
+
+impl<V: SliceLike> SliceLike for UniqRc<V> {
+    type Slice = V::Slice;
 
     fn as_slice(&self) -> &Self::Slice {
         (**self).as_slice()
     }
+}
 
+impl<V: SliceLikeMut> SliceLikeMut for UniqRc<V> {
     fn as_mut_slice(&mut self) -> &mut Self::Slice {
         (**self).as_mut_slice()
     }
+}
+
+impl<V: Truncatable> Truncatable for UniqRc<V> {
+    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        (**self).pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        (**self).remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        (**self).truncate(len);
+    }
+
+    fn clear(&mut self) {
+        (**self).clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(V::ElemRef<'_>) -> bool,
+    {
+        (**self).retain(f);
+    }
+}
+
+impl<V: Growable> Growable for UniqRc<V> {
+    type Collection = V::Collection;
+
+    const MAX_CAPACITY: usize = V::MAX_CAPACITY;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         (**self).as_mut_collection()
@@ -34,18 +69,19 @@ impl<V: VecLike> VecLike for UniqRc<V> {
         (**self).capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        (**self).pop()
-    }
-
     #[track_caller]
     fn push(&mut self, value: Self::Elem) {
         (**self).push(value);
     }
 
     #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        (**self).remove(index)
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        (**self).try_push(value)
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        (**self).try_insert(index, element)
     }
 
     #[track_caller]
@@ -73,10 +109,23 @@ impl<V: VecLike> VecLike for UniqRc<V> {
         (**self).shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        (**self).truncate(len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        (**self).append(other);
     }
+}
 
+impl<V: Drainable> Drainable for UniqRc<V> {
+    type Drain<'a> = V::Drain<'a> where Self: 'a;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        (**self).drain(range)
+    }
+}
+
+impl<V: SeqVecLike> SeqVecLike for UniqRc<V> {
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
         (**self).split_off(at)
@@ -95,27 +144,6 @@ impl<V: VecLike> VecLike for UniqRc<V> {
     {
         (**self).resize_with(new_len, f);
     }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        (**self).drain(range)
-    }
-
-    fn clear(&mut self) {
-        (**self).clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        (**self).append(other);
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(V::ElemRef<'_>) -> bool,
-    {
-        (**self).retain(f);
-    }
 }
 impl<V: VecLikeSolid> VecLikeSolid for UniqRc<V> {
     fn swap_remove(&mut self, index: usize) -> Self::Elem {
@@ -135,12 +163,8 @@ impl<V: VecLikeSolid> VecLikeSolid for UniqRc<V> {
     }
 }
 
-impl<V: VecLike> VecLike for UniqArc<V> {
+impl<V: SeqLike> SeqLike for UniqArc<V> {
     type Elem = V::Elem;
-    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
-    type Slice = V::Slice;
-    type Collection = V::Collection;
-    type Drain<'a> = V::Drain<'a> where Self: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -151,14 +175,53 @@ impl<V: VecLike> VecLike for UniqArc<V> {
     fn is_empty(&self) -> bool {
         (**self).is_empty()
     }
+}
+
+impl<V: SliceLike> SliceLike for UniqArc<V> {
+    type Slice = V::Slice;
 
     fn as_slice(&self) -> &Self::Slice {
         (**self).as_slice()
     }
+}
 
+impl<V: SliceLikeMut> SliceLikeMut for UniqArc<V> {
     fn as_mut_slice(&mut self) -> &mut Self::Slice {
         (**self).as_mut_slice()
     }
+}
+
+impl<V: Truncatable> Truncatable for UniqArc<V> {
+    type ElemRef<'a> = V::ElemRef<'a> where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        (**self).pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        (**self).remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        (**self).truncate(len);
+    }
+
+    fn clear(&mut self) {
+        (**self).clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(V::ElemRef<'_>) -> bool,
+    {
+        (**self).retain(f);
+    }
+}
+
+impl<V: Growable> Growable for UniqArc<V> {
+    type Collection = V::Collection;
+
+    const MAX_CAPACITY: usize = V::MAX_CAPACITY;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         (**self).as_mut_collection()
@@ -168,18 +231,19 @@ impl<V: VecLike> VecLike for UniqArc<V> {
         (**self).capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        (**self).pop()
-    }
-
     #[track_caller]
     fn push(&mut self, value: Self::Elem) {
         (**self).push(value);
     }
 
     #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        (**self).remove(index)
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        (**self).try_push(value)
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        (**self).try_insert(index, element)
     }
 
     #[track_caller]
@@ -207,10 +271,23 @@ impl<V: VecLike> VecLike for UniqArc<V> {
         (**self).shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        (**self).truncate(len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        (**self).append(other);
     }
+}
 
+impl<V: Drainable> Drainable for UniqArc<V> {
+    type Drain<'a> = V::Drain<'a> where Self: 'a;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        (**self).drain(range)
+    }
+}
+
+impl<V: SeqVecLike> SeqVecLike for UniqArc<V> {
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
         (**self).split_off(at)
@@ -229,27 +306,6 @@ impl<V: VecLike> VecLike for UniqArc<V> {
     {
         (**self).resize_with(new_len, f);
     }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        (**self).drain(range)
-    }
-
-    fn clear(&mut self) {
-        (**self).clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        (**self).append(other);
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(V::ElemRef<'_>) -> bool,
-    {
-        (**self).retain(f);
-    }
 }
 impl<V: VecLikeSolid> VecLikeSolid for UniqArc<V> {
     fn swap_remove(&mut self, index: usize) -> Self::Elem {
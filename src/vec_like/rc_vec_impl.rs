@@ -2,12 +2,8 @@ use core::ops::RangeBounds;
 use rc_vec::{ArcVec, ArcVecDrain, RcVec, RcVecDrain};
 use super::*;
 
-impl<T> VecLike for RcVec<T> {
+impl<T> SeqLike for RcVec<T> {
     type Elem = T;
-    type ElemRef<'a> = &'a T where Self: 'a;
-    type Slice = [T];
-    type Collection = RcVec<T>;
-    type Drain<'a> = RcVecDrain<'a, T> where Self: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -18,14 +14,51 @@ impl<T> VecLike for RcVec<T> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+}
+
+impl<T> SliceLike for RcVec<T> {
+    type Slice = [T];
 
     fn as_slice(&self) -> &[Self::Elem] {
         self
     }
+}
 
+impl<T> SliceLikeMut for RcVec<T> {
     fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
         self
     }
+}
+
+impl<T> Truncatable for RcVec<T> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<T> Growable for RcVec<T> {
+    type Collection = RcVec<T>;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         self
@@ -35,20 +68,11 @@ impl<T> VecLike for RcVec<T> {
         self.capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        self.pop()
-    }
-
     #[track_caller]
     fn push(&mut self, value: Self::Elem) {
         self.push(value);
     }
 
-    #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        self.remove(index)
-    }
-
     fn insert(&mut self, index: usize, element: Self::Elem) {
         self.insert(index, element);
     }
@@ -73,10 +97,23 @@ impl<T> VecLike for RcVec<T> {
         self.shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        self.truncate(len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
     }
+}
 
+impl<T> Drainable for RcVec<T> {
+    type Drain<'a> = RcVecDrain<'a, T> where Self: 'a;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<T> SeqVecLike for RcVec<T> {
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
         self.split_off(at)
@@ -95,27 +132,6 @@ impl<T> VecLike for RcVec<T> {
     {
         self.resize_with(new_len, f);
     }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        self.drain(range)
-    }
-
-    fn clear(&mut self) {
-        self.clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        self.append(other);
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(&T) -> bool,
-    {
-        self.retain(f);
-    }
 }
 impl<T> VecLikeSolid for RcVec<T> {
     fn swap_remove(&mut self, index: usize) -> Self::Elem {
@@ -135,12 +151,8 @@ impl<T> VecLikeSolid for RcVec<T> {
     }
 }
 
-impl<T> VecLike for ArcVec<T> {
+impl<T> SeqLike for ArcVec<T> {
     type Elem = T;
-    type ElemRef<'a> = &'a T where Self: 'a;
-    type Slice = [T];
-    type Collection = ArcVec<T>;
-    type Drain<'a> = ArcVecDrain<'a, T> where Self: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -151,14 +163,51 @@ impl<T> VecLike for ArcVec<T> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+}
+
+impl<T> SliceLike for ArcVec<T> {
+    type Slice = [T];
 
     fn as_slice(&self) -> &[Self::Elem] {
         self
     }
+}
 
+impl<T> SliceLikeMut for ArcVec<T> {
     fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
         self
     }
+}
+
+impl<T> Truncatable for ArcVec<T> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<T> Growable for ArcVec<T> {
+    type Collection = ArcVec<T>;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         self
@@ -168,20 +217,11 @@ impl<T> VecLike for ArcVec<T> {
         self.capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        self.pop()
-    }
-
     #[track_caller]
     fn push(&mut self, value: Self::Elem) {
         self.push(value);
     }
 
-    #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        self.remove(index)
-    }
-
     fn insert(&mut self, index: usize, element: Self::Elem) {
         self.insert(index, element);
     }
@@ -206,10 +246,23 @@ impl<T> VecLike for ArcVec<T> {
         self.shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        self.truncate(len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
     }
+}
 
+impl<T> Drainable for ArcVec<T> {
+    type Drain<'a> = ArcVecDrain<'a, T> where Self: 'a;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<T> SeqVecLike for ArcVec<T> {
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
         self.split_off(at)
@@ -228,27 +281,6 @@ impl<T> VecLike for ArcVec<T> {
     {
         self.resize_with(new_len, f);
     }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        self.drain(range)
-    }
-
-    fn clear(&mut self) {
-        self.clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        self.append(other);
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(&T) -> bool,
-    {
-        self.retain(f);
-    }
 }
 impl<T> VecLikeSolid for ArcVec<T> {
     fn swap_remove(&mut self, index: usize) -> Self::Elem {
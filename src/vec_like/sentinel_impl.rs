@@ -0,0 +1,200 @@
+use core::{iter::{repeat_n, repeat_with}, ops::RangeBounds};
+use crate::{util::normalize_range, SentinelDrain, SentinelVec};
+use super::*;
+
+impl<T: Clone> SeqLike for SentinelVec<T> {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T: Clone> SliceLike for SentinelVec<T> {
+    type Slice = [T];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+impl<T: Clone> SliceLikeMut for SentinelVec<T> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+impl<T: Clone> Truncatable for SentinelVec<T> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        Some(self.vec.swap_remove(len-1))
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        let len = self.len();
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+        self.vec.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        let old_len = self.len();
+        if len < old_len {
+            self.vec.drain(len..old_len);
+        }
+    }
+
+    fn clear(&mut self) {
+        Truncatable::truncate(self, 0);
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        let mut i = 0..self.len();
+        self.vec.retain(|elem| {
+            i.next().is_none() || f(elem)
+        });
+    }
+}
+
+impl<T: Clone> Growable for SentinelVec<T> {
+    type Collection = SentinelVec<T>;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.vec.capacity() - 1
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.vec.insert(self.len(), value);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        let len = self.len();
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        self.vec.insert(index, element);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.vec.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.vec.shrink_to(min_capacity.saturating_add(1))
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        let other_len = other.len();
+        self.extend(other.vec.drain(..other_len));
+    }
+}
+
+impl<T: Clone> Drainable for SentinelVec<T> {
+    type Drain<'a> = SentinelDrain<'a, T> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let range = normalize_range(range, self.len());
+        SentinelDrain::new(&mut self.vec, range)
+    }
+}
+
+impl<T: Clone> SeqVecLike for SentinelVec<T> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        let len = self.len();
+        if at > len {
+            panic!("`at` split index (is {at}) should be <= len (is {len})");
+        }
+        let other = self.vec.split_off(at);
+        self.vec.push(other.last().unwrap().clone());
+        Self { vec: other }
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.vec.splice(len..len, repeat_n(value, new_len-len));
+        } else {
+            Truncatable::truncate(self, new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.vec.splice(len..len, repeat_with(f).take(new_len-len));
+        } else {
+            Truncatable::truncate(self, new_len);
+        }
+    }
+}
+impl<T: Clone> VecLikeSolid for SentinelVec<T> {
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Elem {
+        let len = self.len();
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+        self.vec.swap(index, len-1);
+        self.vec.remove(len-1)
+    }
+
+    fn retain_mut<F>(&mut self, mut f: F)
+    where F: FnMut(&mut Self::Elem) -> bool,
+    {
+        let mut i = 0..self.len();
+        self.vec.retain_mut(|elem| {
+            i.next().is_none() || f(elem)
+        });
+    }
+
+    fn pop_if<F>(&mut self, predicate: F) -> Option<Self::Elem>
+    where F: FnOnce(&mut Self::Elem) -> bool,
+    {
+        let last = self.last_mut()?;
+        if predicate(last) { Truncatable::pop(self) } else { None }
+    }
+}
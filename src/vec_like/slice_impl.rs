@@ -0,0 +1,107 @@
+use super::*;
+
+impl<T> SeqLike for [T] {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> SliceLike for [T] {
+    type Slice = [T];
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> SliceLikeMut for [T] {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> SeqLike for [T; N] {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<T, const N: usize> SliceLike for [T; N] {
+    type Slice = [T];
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> SliceLikeMut for [T; N] {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl SeqLike for str {
+    type Elem = char;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl SliceLike for str {
+    type Slice = str;
+
+    fn as_slice(&self) -> &str {
+        self
+    }
+}
+
+impl SliceLikeMut for str {
+    fn as_mut_slice(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl<V: ?Sized + SeqLike> SeqLike for &V {
+    type Elem = V::Elem;
+
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+impl<V: ?Sized + SliceLike> SliceLike for &V {
+    type Slice = V::Slice;
+
+    fn as_slice(&self) -> &Self::Slice {
+        (**self).as_slice()
+    }
+}
@@ -3,12 +3,8 @@ use smallstr::{SmallString, DrainRange};
 use smallvec::Array;
 use super::*;
 
-impl<A: Array<Item = u8>> VecLike for SmallString<A> {
+impl<A: Array<Item = u8>> SeqLike for SmallString<A> {
     type Elem = char;
-    type ElemRef<'a> = char where Self: 'a;
-    type Slice = str;
-    type Collection = Self;
-    type Drain<'a> = DrainRange<'a, A> where A: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -19,14 +15,54 @@ impl<A: Array<Item = u8>> VecLike for SmallString<A> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+}
+
+impl<A: Array<Item = u8>> SliceLike for SmallString<A> {
+    type Slice = str;
 
     fn as_slice(&self) -> &Self::Slice {
         self
     }
+}
 
+impl<A: Array<Item = u8>> SliceLikeMut for SmallString<A> {
     fn as_mut_slice(&mut self) -> &mut Self::Slice {
         self
     }
+}
+
+impl<A: Array<Item = u8>> Truncatable for SmallString<A> {
+    type ElemRef<'a> = char where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, idx: usize) -> Self::Elem {
+        self.remove(idx)
+    }
+
+    fn truncate(&mut self, new_len: usize) {
+        // SmallString::truncate panics beyond the length
+        if new_len < self.len() {
+            self.truncate(new_len);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(Self::Elem) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<A: Array<Item = u8>> Growable for SmallString<A> {
+    type Collection = Self;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         self
@@ -36,20 +72,11 @@ impl<A: Array<Item = u8>> VecLike for SmallString<A> {
         self.capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        self.pop()
-    }
-
     #[track_caller]
     fn push(&mut self, ch: Self::Elem) {
         self.push(ch);
     }
 
-    #[track_caller]
-    fn remove(&mut self, idx: usize) -> Self::Elem {
-        self.remove(idx)
-    }
-
     #[track_caller]
     fn insert(&mut self, idx: usize, ch: Self::Elem) {
         self.insert(idx, ch);
@@ -70,33 +97,14 @@ impl<A: Array<Item = u8>> VecLike for SmallString<A> {
         self.shrink_to_fit()
     }
 
-    fn truncate(&mut self, new_len: usize) {
-        self.truncate(new_len);
-    }
-
     #[track_caller]
-    fn resize(&mut self, new_len: usize, value: Self::Elem)
-    where Self::Elem: Clone,
-    {
-        let len = self.len();
-        if new_len > len {
-            self.extend(repeat_n(value, new_len-len));
-        } else {
-            self.truncate(new_len);
-        }
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.push_str(other)
     }
+}
 
-    #[track_caller]
-    fn resize_with<F>(&mut self, new_len: usize, f: F)
-    where F: FnMut() -> Self::Elem,
-    {
-        let len = self.len();
-        if new_len > len {
-            self.extend(repeat_with(f).take(new_len-len));
-        } else {
-            self.truncate(new_len);
-        }
-    }
+impl<A: Array<Item = u8>> Drainable for SmallString<A> {
+    type Drain<'a> = DrainRange<'a, A> where A: 'a;
 
     // FIXME: Replace to unimplemented smallstr::drain_range
     #[track_caller]
@@ -117,20 +125,36 @@ impl<A: Array<Item = u8>> VecLike for SmallString<A> {
         let _ = self.as_str()[range.clone()];
         self.drain_range(range)
     }
+}
 
-    fn clear(&mut self) {
-        self.clear();
+impl<A: Array<Item = u8>> SeqVecLike for SmallString<A> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        Drainable::drain(self, at..).collect()
     }
 
     #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        self.push_str(other)
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
     }
 
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(Self::Elem) -> bool,
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
     {
-        self.retain(f);
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
     }
 }
 
@@ -139,13 +163,13 @@ impl<A: Array<Item = u8>> VecLike for SmallString<A> {
 mod tests {
     use alloc::string::String;
     use smallstr::SmallString;
-    use crate::VecLike;
+    use crate::Drainable;
 
     #[test]
     fn test() {
         let mut s: SmallString<[u8; 0]> = SmallString::from_str("foobar");
         assert_eq!(s, "foobar");
-        let s1: String = VecLike::drain(&mut s, 1..=3).collect();
+        let s1: String = Drainable::drain(&mut s, 1..=3).collect();
         assert_eq!(s1, "oob");
         assert_eq!(s, "far");
     }
@@ -154,7 +178,7 @@ mod tests {
     fn test_rev() {
         let mut s: SmallString<[u8; 0]> = SmallString::from_str("foobar");
         assert_eq!(s, "foobar");
-        let s1: String = VecLike::drain(&mut s, 1..=3).rev().collect();
+        let s1: String = Drainable::drain(&mut s, 1..=3).rev().collect();
         assert_eq!(s1, "boo");
         assert_eq!(s, "far");
     }
@@ -163,7 +187,7 @@ mod tests {
     fn test_empty() {
         let mut s: SmallString<[u8; 0]> = SmallString::new();
         assert_eq!(s, "");
-        let s1: String = VecLike::drain(&mut s, 0..0).collect();
+        let s1: String = Drainable::drain(&mut s, 0..0).collect();
         assert_eq!(s1, "");
         assert_eq!(s, "");
     }
@@ -172,7 +196,7 @@ mod tests {
     fn test_multi_bytes() {
         let mut s: SmallString<[u8; 0]> = SmallString::from_str("从前有座山");
         assert_eq!(s, "从前有座山");
-        let s1: String = VecLike::drain(&mut s, 6..12).collect();
+        let s1: String = Drainable::drain(&mut s, 6..12).collect();
         assert_eq!(s1, "有座");
         assert_eq!(s, "从前山");
     }
@@ -181,7 +205,7 @@ mod tests {
     fn test_multi_bytes_rev() {
         let mut s: SmallString<[u8; 0]> = SmallString::from_str("从前有座山");
         assert_eq!(s, "从前有座山");
-        let s1: String = VecLike::drain(&mut s, 6..12).rev().collect();
+        let s1: String = Drainable::drain(&mut s, 6..12).rev().collect();
         assert_eq!(s1, "座有");
         assert_eq!(s, "从前山");
     }
@@ -190,7 +214,7 @@ mod tests {
     fn some_consume() {
         let mut s: SmallString<[u8; 0]> = SmallString::from_str("foobar");
         assert_eq!(s, "foobar");
-        let ch = VecLike::drain(&mut s, 1..=3).next();
+        let ch = Drainable::drain(&mut s, 1..=3).next();
         assert_eq!(ch, Some('o'));
         assert_eq!(s, "far");
     }
@@ -199,7 +223,7 @@ mod tests {
     fn no_consume() {
         let mut s: SmallString<[u8; 0]> = SmallString::from_str("foobar");
         assert_eq!(s, "foobar");
-        VecLike::drain(&mut s, 1..=3);
+        Drainable::drain(&mut s, 1..=3);
         assert_eq!(s, "far");
     }
 
@@ -207,7 +231,18 @@ mod tests {
     fn size_hint() {
         let mut s: SmallString<[u8; 0]> = SmallString::from_str("foobar");
         assert_eq!(s, "foobar");
-        let size_hint = VecLike::drain(&mut s, 1..=3).size_hint();
+        let size_hint = Drainable::drain(&mut s, 1..=3).size_hint();
         assert_eq!(size_hint, (1, Some(3)));
     }
+
+    #[test]
+    fn conformance() {
+        use crate::testing::{check_offset, check_vec_like};
+
+        let samples = ['f', 'o', 'o', 'b', 'a', 'r'];
+        check_vec_like::<SmallString<[u8; 0]>>(&samples);
+        check_vec_like::<SmallString<[u8; 16]>>(&samples);
+        check_offset::<SmallString<[u8; 0]>>(&samples);
+        check_offset::<SmallString<[u8; 4]>>(&samples);
+    }
 }
@@ -1,12 +1,8 @@
 use smallvec::{Array, SmallVec};
 use super::*;
 
-impl<A: Array> VecLike for SmallVec<A> {
+impl<A: Array> SeqLike for SmallVec<A> {
     type Elem = A::Item;
-    type ElemRef<'a> = &'a A::Item where Self: 'a;
-    type Slice = [A::Item];
-    type Collection = SmallVec<A>;
-    type Drain<'a> = smallvec::Drain<'a, A> where Self: 'a;
 
     #[inline]
     fn len(&self) -> usize {
@@ -17,14 +13,51 @@ impl<A: Array> VecLike for SmallVec<A> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+}
+
+impl<A: Array> SliceLike for SmallVec<A> {
+    type Slice = [A::Item];
 
     fn as_slice(&self) -> &[Self::Elem] {
         self
     }
+}
 
+impl<A: Array> SliceLikeMut for SmallVec<A> {
     fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
         self
     }
+}
+
+impl<A: Array> Truncatable for SmallVec<A> {
+    type ElemRef<'a> = &'a A::Item where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&A::Item) -> bool,
+    {
+        self.retain(|elem| f(&*elem));
+    }
+}
+
+impl<A: Array> Growable for SmallVec<A> {
+    type Collection = SmallVec<A>;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         self
@@ -34,20 +67,11 @@ impl<A: Array> VecLike for SmallVec<A> {
         self.capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        self.pop()
-    }
-
     #[track_caller]
     fn push(&mut self, value: Self::Elem) {
         self.push(value);
     }
 
-    #[track_caller]
-    fn remove(&mut self, index: usize) -> Self::Elem {
-        self.remove(index)
-    }
-
     fn insert(&mut self, index: usize, element: Self::Elem) {
         self.insert(index, element);
     }
@@ -67,43 +91,40 @@ impl<A: Array> VecLike for SmallVec<A> {
         self.shrink_to_fit()
     }
 
-    fn truncate(&mut self, len: usize) {
-        self.truncate(len);
-    }
-
     #[track_caller]
-    fn resize(&mut self, new_len: usize, value: Self::Elem)
-    where Self::Elem: Clone,
-    {
-        self.resize(new_len, value);
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
     }
+}
 
-    #[track_caller]
-    fn resize_with<F>(&mut self, new_len: usize, f: F)
-    where F: FnMut() -> Self::Elem,
-    {
-        self.resize_with(new_len, f);
-    }
+impl<A: Array> Drainable for SmallVec<A> {
+    type Drain<'a> = smallvec::Drain<'a, A> where Self: 'a;
 
     fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
     where R: RangeBounds<usize>,
     {
         self.drain(range)
     }
+}
 
-    fn clear(&mut self) {
-        self.clear();
+impl<A: Array> SeqVecLike for SmallVec<A> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.drain(at..).collect()
     }
 
     #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        self.append(other);
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize(new_len, value);
     }
 
-    fn retain<F>(&mut self, mut f: F)
-    where F: FnMut(&A::Item) -> bool,
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
     {
-        self.retain(|elem| f(&*elem));
+        self.resize_with(new_len, f);
     }
 }
 impl<A: Array> VecLikeSolid for SmallVec<A> {
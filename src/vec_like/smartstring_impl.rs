@@ -0,0 +1,160 @@
+use core::{iter::{repeat_n, repeat_with}, ops::RangeBounds};
+use smartstring::{Drain, SmartString, SmartStringMode};
+use crate::util::normalize_range;
+use super::*;
+
+impl<Mode: SmartStringMode> SeqLike for SmartString<Mode> {
+    type Elem = char;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<Mode: SmartStringMode> SliceLike for SmartString<Mode> {
+    type Slice = str;
+
+    fn as_slice(&self) -> &Self::Slice {
+        self
+    }
+}
+
+impl<Mode: SmartStringMode> SliceLikeMut for SmartString<Mode> {
+    fn as_mut_slice(&mut self) -> &mut Self::Slice {
+        self
+    }
+}
+
+impl<Mode: SmartStringMode> Truncatable for SmartString<Mode> {
+    type ElemRef<'a> = char where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, idx: usize) -> Self::Elem {
+        self.remove(idx)
+    }
+
+    #[track_caller]
+    fn truncate(&mut self, new_len: usize) {
+        self.truncate(new_len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(Self::Elem) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<Mode: SmartStringMode> Growable for SmartString<Mode> {
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, ch: Self::Elem) {
+        self.push(ch);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, idx: usize, ch: Self::Elem) {
+        self.insert(idx, ch);
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.push_str(other);
+        other.clear();
+    }
+}
+
+impl<Mode: SmartStringMode> Drainable for SmartString<Mode> {
+    type Drain<'a> = Drain<'a, Mode> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        let range = normalize_range(range, self.len());
+        let _ = self.as_str()[range.clone()];
+        self.drain(range)
+    }
+}
+
+impl<Mode: SmartStringMode> SeqVecLike for SmartString<Mode> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use smartstring::alias::String as SmartString;
+    use crate::Drainable;
+
+    #[test]
+    fn test_multi_bytes() {
+        let mut s = SmartString::from("从前有座山");
+        let s1: String = Drainable::drain(&mut s, 6..12).collect();
+        assert_eq!(s1, "有座");
+        assert_eq!(s, "从前山");
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_char_boundary() {
+        let mut s = SmartString::from("从前有座山");
+        Drainable::drain(&mut s, 1..);
+    }
+}
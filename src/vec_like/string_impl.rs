@@ -2,12 +2,8 @@ use core::{iter::{repeat_n, repeat_with}, ops::RangeBounds};
 use alloc::string::{Drain, String};
 use super::*;
 
-impl VecLike for String {
+impl SeqLike for String {
     type Elem = char;
-    type ElemRef<'a> = char where Self: 'a;
-    type Slice = str;
-    type Collection = String;
-    type Drain<'a> = Drain<'a>;
 
     #[inline]
     fn len(&self) -> usize {
@@ -18,14 +14,51 @@ impl VecLike for String {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+}
+
+impl SliceLike for String {
+    type Slice = str;
 
     fn as_slice(&self) -> &Self::Slice {
         self
     }
+}
 
+impl SliceLikeMut for String {
     fn as_mut_slice(&mut self) -> &mut Self::Slice {
         self
     }
+}
+
+impl Truncatable for String {
+    type ElemRef<'a> = char where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, idx: usize) -> Self::Elem {
+        self.remove(idx)
+    }
+
+    fn truncate(&mut self, new_len: usize) {
+        self.truncate(new_len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(Self::Elem) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl Growable for String {
+    type Collection = String;
 
     fn as_mut_collection(&mut self) -> &mut Self::Collection {
         self
@@ -35,20 +68,11 @@ impl VecLike for String {
         self.capacity()
     }
 
-    fn pop(&mut self) -> Option<Self::Elem> {
-        self.pop()
-    }
-
     #[track_caller]
     fn push(&mut self, ch: Self::Elem) {
         self.push(ch);
     }
 
-    #[track_caller]
-    fn remove(&mut self, idx: usize) -> Self::Elem {
-        self.remove(idx)
-    }
-
     #[track_caller]
     fn insert(&mut self, idx: usize, ch: Self::Elem) {
         self.insert(idx, ch);
@@ -74,10 +98,23 @@ impl VecLike for String {
         self.shrink_to_fit()
     }
 
-    fn truncate(&mut self, new_len: usize) {
-        self.truncate(new_len);
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.push_str(other)
     }
+}
 
+impl Drainable for String {
+    type Drain<'a> = Drain<'a>;
+
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl SeqVecLike for String {
     #[track_caller]
     fn split_off(&mut self, at: usize) -> Self::Collection {
         self.split_off(at)
@@ -106,25 +143,4 @@ impl VecLike for String {
             self.truncate(new_len);
         }
     }
-
-    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
-    where R: RangeBounds<usize>,
-    {
-        self.drain(range)
-    }
-
-    fn clear(&mut self) {
-        self.clear();
-    }
-
-    #[track_caller]
-    fn append(&mut self, other: &mut Self::Collection) {
-        self.push_str(other)
-    }
-
-    fn retain<F>(&mut self, f: F)
-    where F: FnMut(Self::Elem) -> bool,
-    {
-        self.retain(f);
-    }
 }
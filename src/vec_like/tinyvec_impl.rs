@@ -0,0 +1,460 @@
+use core::ops::RangeBounds;
+use tinyvec::{Array, ArrayVec, ArrayVecDrain, SliceVec, SliceVecDrain};
+#[cfg(feature = "alloc")]
+use tinyvec::{TinyVec, TinyVecDrain};
+use crate::array_vec::capacity_overflow;
+use super::*;
+
+impl<A: Array> SeqLike for ArrayVec<A> {
+    type Elem = A::Item;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<A: Array> SliceLike for ArrayVec<A> {
+    type Slice = [A::Item];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+impl<A: Array> SliceLikeMut for ArrayVec<A> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+impl<A: Array> Truncatable for ArrayVec<A> {
+    type ElemRef<'a> = &'a A::Item where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&A::Item) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<A: Array> Growable for ArrayVec<A> {
+    const MAX_CAPACITY: usize = A::CAPACITY;
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        A::CAPACITY
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        if additional > A::CAPACITY - self.len() {
+            capacity_overflow(A::CAPACITY)
+        }
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        Growable::reserve(self, additional);
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        match self.try_push(value) {
+            Some(value) => Err(CapacityError::new(value)),
+            None => Ok(()),
+        }
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        match self.try_insert(index, element) {
+            Some(element) => Err(CapacityError::new(element)),
+            None => Ok(()),
+        }
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
+    }
+}
+
+impl<A: Array> Drainable for ArrayVec<A> {
+    type Drain<'a> = ArrayVecDrain<'a, A::Item> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<A: Array> SeqVecLike for ArrayVec<A> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize(new_len, value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        self.resize_with(new_len, f);
+    }
+}
+
+impl<T: Default> SeqLike for SliceVec<'_, T> {
+    type Elem = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T: Default> SliceLike for SliceVec<'_, T> {
+    type Slice = [T];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+impl<T: Default> SliceLikeMut for SliceVec<'_, T> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+impl<T: Default> Truncatable for SliceVec<'_, T> {
+    type ElemRef<'a> = &'a T where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&T) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl<'s, T: Default> Growable for SliceVec<'s, T> {
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        if additional > self.capacity() - self.len() {
+            capacity_overflow(self.capacity())
+        }
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        Growable::reserve(self, additional);
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    fn try_push(&mut self, value: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        if self.is_full() {
+            return Err(CapacityError::new(value));
+        }
+        self.push(value);
+        Ok(())
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn try_insert(&mut self, index: usize, element: Self::Elem) -> Result<(), CapacityError<Self::Elem>> {
+        let len = self.len();
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        if self.is_full() {
+            return Err(CapacityError::new(element));
+        }
+        self.insert(index, element);
+        Ok(())
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        Growable::reserve(self, other.len());
+        self.append(other);
+    }
+}
+
+impl<'s, T: Default> Drainable for SliceVec<'s, T> {
+    type Drain<'a> = SliceVecDrain<'a, 's, T> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl<T: Default> SeqVecLike for SliceVec<'_, T> {
+    /// Split the buffer, like [`SliceVec::split_off`]
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize(new_len, value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        self.resize_with(new_len, f);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A: Array> SeqLike for TinyVec<A> {
+    type Elem = A::Item;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A: Array> SliceLike for TinyVec<A> {
+    type Slice = [A::Item];
+
+    fn as_slice(&self) -> &[Self::Elem] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A: Array> SliceLikeMut for TinyVec<A> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A: Array> Truncatable for TinyVec<A> {
+    type ElemRef<'a> = &'a A::Item where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Elem {
+        self.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(&A::Item) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A: Array> Growable for TinyVec<A> {
+    type Collection = Self;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: Self::Elem) {
+        self.push(value);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, element: Self::Elem) {
+        self.insert(index, element);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.append(other);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A: Array> Drainable for TinyVec<A> {
+    type Drain<'a> = TinyVecDrain<'a, A> where Self: 'a;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A: Array> SeqVecLike for TinyVec<A> {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        self.resize(new_len, value);
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        self.resize_with(new_len, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinyvec::{array_vec, ArrayVec, SliceVec};
+    use crate::{CapacityError, Growable};
+
+    #[test]
+    fn fallible_growth() {
+        let mut vec: ArrayVec<[i32; 2]> = array_vec![1];
+        assert_eq!(Growable::try_insert(&mut vec, 0, 0), Ok(()));
+        assert_eq!(Growable::try_push(&mut vec, 2), Err(CapacityError::new(2)));
+        assert_eq!(vec, &[0, 1][..]);
+
+        let mut buf = [0; 2];
+        let mut vec = SliceVec::from_slice_len(&mut buf, 1);
+        assert_eq!(Growable::try_push(&mut vec, 3), Ok(()));
+        assert_eq!(Growable::try_insert(&mut vec, 0, 4), Err(CapacityError::new(4)));
+        assert_eq!(vec, &[0, 3][..]);
+    }
+}
@@ -0,0 +1,149 @@
+use core::{iter::{repeat_n, repeat_with}, ops::RangeBounds};
+use crate::{Utf16Drain, Utf16Str, Utf16String};
+use super::*;
+
+impl SeqLike for Utf16String {
+    type Elem = char;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_utf16_str().len()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_utf16_str().is_empty()
+    }
+}
+
+impl SliceLike for Utf16String {
+    type Slice = Utf16Str;
+
+    fn as_slice(&self) -> &Self::Slice {
+        self
+    }
+}
+
+impl SliceLikeMut for Utf16String {
+    fn as_mut_slice(&mut self) -> &mut Self::Slice {
+        self
+    }
+}
+
+impl Truncatable for Utf16String {
+    type ElemRef<'a> = char where Self: 'a;
+
+    fn pop(&mut self) -> Option<Self::Elem> {
+        self.pop()
+    }
+
+    #[track_caller]
+    fn remove(&mut self, idx: usize) -> Self::Elem {
+        self.remove(idx)
+    }
+
+    #[track_caller]
+    fn truncate(&mut self, new_len: usize) {
+        self.truncate(new_len);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where F: FnMut(Self::Elem) -> bool,
+    {
+        self.retain(f);
+    }
+}
+
+impl Growable for Utf16String {
+    type Collection = Utf16String;
+
+    fn as_mut_collection(&mut self) -> &mut Self::Collection {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[track_caller]
+    fn push(&mut self, ch: Self::Elem) {
+        self.push(ch);
+    }
+
+    #[track_caller]
+    fn insert(&mut self, idx: usize, ch: Self::Elem) {
+        self.insert(idx, ch);
+    }
+
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    #[track_caller]
+    fn append(&mut self, other: &mut Self::Collection) {
+        self.push_utf16_str(other);
+        other.clear();
+    }
+}
+
+impl Drainable for Utf16String {
+    type Drain<'a> = Utf16Drain<'a>;
+
+    #[track_caller]
+    fn drain<R>(&mut self, range: R) -> Self::Drain<'_>
+    where R: RangeBounds<usize>,
+    {
+        self.drain(range)
+    }
+}
+
+impl SeqVecLike for Utf16String {
+    #[track_caller]
+    fn split_off(&mut self, at: usize) -> Self::Collection {
+        self.split_off(at)
+    }
+
+    #[track_caller]
+    fn resize(&mut self, new_len: usize, value: Self::Elem)
+    where Self::Elem: Clone,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_n(value, new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where F: FnMut() -> Self::Elem,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(repeat_with(f).take(new_len-len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
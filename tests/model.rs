@@ -0,0 +1,326 @@
+//! Model-based differential tests, random operations through nested [`OffsetVec`] windows
+//! are run against each backend and a plain [`Vec`] model
+#![cfg(feature = "alloc")]
+
+use offset_vec::*;
+use std::{
+    any::Any,
+    borrow::Cow,
+    fmt::Debug,
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
+
+const SEEDS: u64 = 24;
+const STEPS: usize = 160;
+
+const BYTES: &[u8] = &[0, 1, 2, 3, 0xfe, 0xff];
+const CHARS: &[char] = &['a', 'z', 'é', 'ß', '你', '好', '🦀'];
+
+/// Deterministic xorshift64*, no external crates
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// In `0..=max`
+    fn upto(&mut self, max: usize) -> usize {
+        (self.next() % (max as u64 + 1)) as usize
+    }
+
+    fn pick<T: Clone>(&mut self, samples: &[T]) -> T {
+        samples[self.upto(samples.len()-1)].clone()
+    }
+}
+
+/// Element of the model, width is the index units of the backend, e.g UTF-8 bytes of char
+trait Unit: Clone + PartialEq + Debug {
+    fn width(&self) -> usize;
+}
+
+impl Unit for u8 {
+    fn width(&self) -> usize {
+        1
+    }
+}
+
+impl Unit for char {
+    fn width(&self) -> usize {
+        self.len_utf8()
+    }
+}
+
+fn units<E: Unit>(elems: &[E]) -> usize {
+    elems.iter().map(Unit::width).sum()
+}
+
+/// Read the contents of [`SliceLike::as_slice`] as model elements
+trait Contents {
+    type Elem;
+
+    fn elems(&self) -> Vec<Self::Elem>;
+}
+
+impl<T: Clone> Contents for [T] {
+    type Elem = T;
+
+    fn elems(&self) -> Vec<T> {
+        self.to_vec()
+    }
+}
+
+impl Contents for str {
+    type Elem = char;
+
+    fn elems(&self) -> Vec<char> {
+        self.chars().collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Op<E> {
+    Push(E),
+    Pop,
+    Insert(usize, E),
+    Remove(usize),
+    Drain(usize, usize),
+    SplitOff(usize),
+    Retain(u64),
+    Grow(usize, E),
+    Truncate(usize),
+    Clear,
+}
+
+impl<E: Unit> Op<E> {
+    fn random(rng: &mut Rng, len: usize, samples: &[E]) -> Self {
+        match rng.upto(17) {
+            0..=2 => Op::Push(rng.pick(samples)),
+            3 => Op::Pop,
+            4..=6 => Op::Insert(rng.upto(len), rng.pick(samples)),
+            7..=9 => Op::Remove(rng.upto(len)),
+            10 | 11 => {
+                let start = rng.upto(len);
+                Op::Drain(start, start+rng.upto(len-start))
+            },
+            12 => Op::SplitOff(rng.upto(len)),
+            13 => Op::Retain(rng.next()),
+            14 => Op::Grow(rng.upto(3), rng.pick(samples)),
+            15 | 16 => Op::Truncate(rng.upto(len+1)),
+            _ => Op::Clear,
+        }
+    }
+}
+
+fn panic_message(e: &(dyn Any + Send)) -> &str {
+    e.downcast_ref::<String>().map(String::as_str)
+        .or_else(|| e.downcast_ref::<&str>().copied())
+        .unwrap_or("non-string panic")
+}
+
+/// Apply `op` to the window `w`, which is `model[base..]`,
+/// `windowed` expects out of bounds are reported by [`OffsetVec`] instead of the backend
+#[track_caller]
+fn apply<W>(w: &mut W, model: &mut Vec<W::Elem>, base: usize, windowed: bool, op: Op<W::Elem>)
+where W: SeqVecLike,
+      W::Elem: Unit,
+      W::Collection: SliceLike,
+{
+    let unit = |model: &Vec<W::Elem>, i: usize| units(&model[base..base+i]);
+    let len = model.len() - base;
+
+    match op {
+        Op::Push(e) => {
+            w.push(e.clone());
+            model.push(e);
+        },
+        Op::Pop => {
+            let expected = if len == 0 { None } else { model.pop() };
+            assert_eq!(w.pop(), expected, "pop");
+        },
+        Op::Insert(i, e) => {
+            w.insert(unit(model, i), e.clone());
+            model.insert(base+i, e);
+        },
+        Op::Remove(i) if i < len => {
+            assert_eq!(w.remove(unit(model, i)), model.remove(base+i), "remove({i})");
+        },
+        Op::Remove(_) => {
+            let i = unit(model, len);
+            let Err(e) = catch_unwind(AssertUnwindSafe(|| w.remove(i))) else {
+                panic!("remove at len {i} not panicked");
+            };
+            if windowed {
+                let msg = panic_message(&*e);
+                assert!(msg.starts_with("offset index"), "remove at len {i} unchecked: {msg}");
+            }
+        },
+        Op::Drain(start, end) => {
+            let range = unit(model, start)..unit(model, end);
+            let drained: Vec<_> = w.drain(range).collect();
+            let expected: Vec<_> = model.drain(base+start..base+end).collect();
+            assert_eq!(drained, expected, "drain({start}..{end})");
+        },
+        Op::SplitOff(at) => {
+            let mut tail = w.split_off(unit(model, at));
+            let expected = model.split_off(base+at);
+            assert_eq!(w.len(), unit(model, at), "split_off({at})");
+            assert_eq!(tail.len(), units(&expected), "split_off({at}) tail");
+            w.append(&mut tail);
+            model.extend(expected);
+        },
+        Op::Retain(mask) => {
+            let mut i = 0;
+            w.retain(|_| { i += 1; mask >> (i % 64) & 1 == 1 });
+            let mut i = 0;
+            let tail = model.split_off(base);
+            model.extend(tail.into_iter().filter(|_| { i += 1; mask >> (i % 64) & 1 == 1 }));
+        },
+        Op::Grow(n, e) => {
+            w.resize(unit(model, len)+n, e.clone());
+            model.extend(std::iter::repeat_n(e, n));
+        },
+        Op::Truncate(n) if n > len => {
+            w.truncate(unit(model, len)+1);
+        },
+        Op::Truncate(n) => {
+            w.truncate(unit(model, n));
+            model.truncate(base+n);
+        },
+        Op::Clear => {
+            w.clear();
+            model.truncate(base);
+        },
+    }
+    assert_eq!(w.len(), unit(model, model.len()-base), "window len");
+}
+
+/// Run random operations through windows of depth 0 to 2, check `vec` against model on each step
+#[track_caller]
+fn check_model<V>(vec: &mut V, seed: u64, samples: &[V::Elem])
+where V: VecLike,
+      V::Slice: Contents<Elem = V::Elem> + SliceMut,
+      V::Elem: Unit,
+      V::Collection: SliceLike,
+{
+    let mut rng = Rng::new(seed);
+    let mut model = vec.as_slice().elems();
+
+    for step in 0..STEPS {
+        let outer = rng.upto(model.len());
+        let inner = outer + rng.upto(model.len()-outer);
+        let op = Op::random(&mut rng, model.len()-inner, samples);
+        let trace = format!("seed {seed} step {step} window {outer}/{inner} {op:?}");
+
+        let result = catch_unwind(AssertUnwindSafe(|| match rng.upto(2) {
+            0 => apply(vec, &mut model, 0, false, op),
+            1 => {
+                let mut w = create(&mut *vec, units(&model[..outer]));
+                assert_eq!(w.origin_offset(), units(&model[..outer]));
+                apply(&mut w, &mut model, outer, true, op);
+            },
+            _ => {
+                let mut w = create(&mut *vec, units(&model[..outer]));
+                let mut w = create(&mut w, units(&model[outer..inner]));
+                apply(&mut w, &mut model, inner, true, op);
+            },
+        }));
+        if let Err(e) = result {
+            panic!("{trace}: {}", panic_message(&*e));
+        }
+
+        assert_eq!(vec.as_slice().elems(), model, "{trace}");
+        assert_eq!(vec.len(), units(&model), "{trace}");
+    }
+}
+
+fn each_seed(mut f: impl FnMut(u64)) {
+    for seed in 0..SEEDS {
+        f(seed);
+    }
+}
+
+#[test]
+fn model_vec() {
+    each_seed(|seed| check_model(&mut Vec::<u8>::new(), seed, BYTES));
+}
+
+#[test]
+fn model_string() {
+    each_seed(|seed| check_model(&mut String::from("héllo, 世界"), seed, CHARS));
+}
+
+#[test]
+fn model_rc() {
+    each_seed(|seed| {
+        let mut vec = Rc::new(BYTES.to_vec());
+        let shared = vec.clone();
+        check_model(&mut vec, seed, BYTES);
+        assert_eq!(*shared, BYTES);
+    });
+}
+
+#[test]
+fn model_arc() {
+    each_seed(|seed| {
+        let mut vec = Arc::new(String::from("你好"));
+        let shared = vec.clone();
+        check_model(&mut vec, seed, CHARS);
+        assert_eq!(*shared, "你好");
+    });
+}
+
+#[test]
+fn model_cow() {
+    each_seed(|seed| {
+        let mut vec = Cow::Borrowed(BYTES);
+        check_model(&mut vec, seed, BYTES);
+        let mut vec = Cow::Borrowed("ünïcode");
+        check_model(&mut vec, seed, CHARS);
+    });
+}
+
+#[test]
+#[cfg(feature = "smallvec")]
+fn model_smallvec() {
+    each_seed(|seed| {
+        check_model(&mut smallvec::SmallVec::<[u8; 4]>::new(), seed, BYTES);
+    });
+}
+
+#[test]
+#[cfg(feature = "smallstr")]
+fn model_smallstr() {
+    each_seed(|seed| {
+        check_model(&mut smallstr::SmallString::<[u8; 4]>::new(), seed, CHARS);
+    });
+}
+
+#[test]
+#[cfg(feature = "rc-vec")]
+fn model_rc_vec() {
+    each_seed(|seed| {
+        check_model(&mut rc_vec::RcVec::<u8>::new(), seed, BYTES);
+        check_model(&mut rc_vec::ArcVec::<u8>::new(), seed, BYTES);
+    });
+}
+
+#[test]
+#[cfg(feature = "unique-rc")]
+fn model_unique_rc() {
+    each_seed(|seed| {
+        check_model(&mut unique_rc::UniqRc::new_value(BYTES.to_vec()), seed, BYTES);
+        check_model(&mut unique_rc::UniqRc::new_value(String::from("ok")), seed, CHARS);
+    });
+}
@@ -1,3 +1,6 @@
+#![cfg(feature = "alloc")]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 use offset_vec::*;
 use std::{borrow::ToOwned, vec};
 
@@ -124,3 +127,752 @@ fn retain_val_fat2() {
     assert_eq!(s.origin_vec(), "测试中");
     assert_eq!(s, "");
 }
+
+#[test]
+fn utf16_retain() {
+    let mut s = Utf16String::from("😀a😂b").offset(2);
+    s.retain(|ch| ch != 'a');
+    assert_eq!(s.origin_vec(), "😀😂b");
+    assert_eq!(s.as_utf16_str(), "😂b");
+}
+
+#[test]
+fn utf16_retain_panic() {
+    let mut s = Utf16String::from("a😀b");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        s.retain(|ch| if ch == 'b' { panic!() } else { ch != 'a' });
+    }));
+    assert!(result.is_err());
+    assert_eq!(s.as_units(), Utf16String::from("😀b").as_units());
+    assert_eq!(s.as_utf16_str(), "😀b");
+}
+
+#[test]
+#[should_panic = "index 1 is not on a UTF-16 char boundary"]
+fn utf16_offset_split_surrogate() {
+    let _ = Utf16String::from("😀a").offset(1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn os_string_window() {
+    use std::ffi::{OsStr, OsString};
+
+    let mut s = OsString::from("a你b");
+    let mut s1 = s.offset_mut(1);
+    assert_eq!(s1.as_os_str(), "你b");
+    assert_eq!(s1.pop(), Some("b".into()));
+    s1.push_os_str("好c".as_ref());
+    assert_eq!(s1.remove(3), "好");
+    assert_eq!(s1.to_string(), "你c");
+    assert_eq!(s, "a你c");
+
+    let mut s = s.offset(1);
+    s.retain(|unit: &OsStr| unit != "c");
+    assert_eq!(s.origin_vec(), "a你");
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic = "byte index 2 is not an OsStr boundary"]
+fn os_string_offset_boundary() {
+    let _ = std::ffi::OsString::from("a你b").offset(2);
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn os_string_non_unicode() {
+    use std::{ffi::{OsStr, OsString}, os::unix::ffi::OsStrExt};
+
+    let mut s = OsStr::from_bytes(b"-\xff\xfe-x").to_owned().offset(1);
+    let drained: Vec<OsString> = s.drain(..3).collect();
+    assert_eq!(drained, [OsStr::from_bytes(b"\xff\xfe"), OsStr::new("-")]);
+    assert_eq!(s.origin_vec(), "-x");
+}
+
+#[test]
+fn sentinel_window() {
+    let mut vec = SentinelVec::new(usize::MAX);
+    vec.extend([1, 2, 3]);
+    let mut vec1 = vec.offset_mut(1);
+    assert_eq!(vec1.remove(0), 2);
+    vec1.insert(1, 4);
+    vec1.truncate(1);
+    assert_eq!(vec1.as_slice_with_sentinel(), [3, usize::MAX]);
+    assert_eq!(vec1.split_off(0).as_slice_with_sentinel(), [3, usize::MAX]);
+    assert_eq!(vec1.pop(), None);
+    assert_eq!(vec.as_slice_with_sentinel(), [1, usize::MAX]);
+}
+
+#[test]
+fn sentinel_retain_drain() {
+    let mut vec = SentinelVec::from_vec(b"-abc-d".to_vec(), 0).offset(1);
+    vec.retain(|&ch| ch != b'-');
+    assert_eq!(vec.drain(1..3).collect::<Vec<_>>(), b"bc");
+    assert_eq!(vec.as_c_str().unwrap(), c"ad");
+    assert_eq!(vec.origin_vec().as_c_str().unwrap(), c"-ad");
+}
+
+#[test]
+fn sentinel_drain_leak() {
+    let mut vec = SentinelVec::from_vec(vec![1, 2, 3, 4], 0);
+    std::mem::forget(vec.offset_mut(1).drain(..2));
+    assert_eq!(vec.len(), 1);
+    assert_eq!(vec.as_slice_with_sentinel(), [1, 0]);
+
+    let mut vec = SentinelVec::from_vec(vec![1, 2, 3, 4], 0);
+    assert_eq!(vec.drain(1..3).rev().collect::<Vec<_>>(), [3, 2]);
+    assert_eq!(vec.as_slice_with_sentinel(), [1, 4, 0]);
+}
+
+#[test]
+fn sentinel_extend_panic() {
+    let mut vec = SentinelVec::new(0);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.extend((1..5).map(|i| if i == 3 { panic!() } else { i }));
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec.as_slice_with_sentinel(), [1, 2, 0]);
+}
+
+#[test]
+fn aligned_vec_window() {
+    let mut buf = AlignedVec::<16>::from(&[1; 40][..]);
+    let mut vec1 = buf.offset_aligned_mut(16);
+    assert!(vec1.is_aligned_to(16));
+    vec1.insert(0, 2);
+    vec1.truncate(4);
+    vec1.reserve(1000);
+    assert!(vec1.is_aligned_to(16));
+    assert_eq!(vec1, [2, 1, 1, 1]);
+    assert_eq!(buf.len(), 20);
+
+    let vec2 = buf.offset_aligned(16);
+    assert!(vec2.is_aligned_to(16));
+    assert_eq!(vec2, [2, 1, 1, 1]);
+}
+
+#[test]
+#[should_panic = "offset 8 is not aligned to 16"]
+fn aligned_vec_unaligned_offset() {
+    let mut buf = AlignedVec::<16>::from(&[0; 32][..]);
+    let _ = buf.offset_aligned_mut(8);
+}
+
+#[test]
+fn array_vec_window() {
+    let mut vec = ArrayVecLike::<i32, 4>::from_iter([0, 1]);
+    let mut vec1 = vec.offset_mut(1);
+    vec1.resize(3, 2);
+    assert_eq!(vec1, [1, 2, 2]);
+    assert_eq!(vec1.try_insert(3, 3).map_err(CapacityError::into_element), Err(3));
+    assert_eq!(vec1.drain(1..).collect::<Vec<_>>(), [2, 2]);
+    assert_eq!(vec, [0, 1]);
+    assert_eq!(<ArrayVecLike<i32, 4> as Growable>::MAX_CAPACITY, 4);
+    assert_eq!(<Vec<i32> as Growable>::MAX_CAPACITY, usize::MAX);
+}
+
+#[test]
+fn shrink_only_window() {
+    #[derive(Debug)]
+    struct ShrinkOnly<'a>(&'a mut [i32], usize);
+
+    impl SeqLike for ShrinkOnly<'_> {
+        type Elem = i32;
+
+        fn len(&self) -> usize {
+            self.1
+        }
+
+        fn is_empty(&self) -> bool {
+            self.1 == 0
+        }
+    }
+
+    impl SliceLike for ShrinkOnly<'_> {
+        type Slice = [i32];
+
+        fn as_slice(&self) -> &[i32] {
+            &self.0[..self.1]
+        }
+    }
+
+    impl SliceLikeMut for ShrinkOnly<'_> {
+        fn as_mut_slice(&mut self) -> &mut [i32] {
+            &mut self.0[..self.1]
+        }
+    }
+
+    impl Truncatable for ShrinkOnly<'_> {
+        type ElemRef<'a> = &'a i32 where Self: 'a;
+
+        fn truncate(&mut self, len: usize) {
+            self.1 = self.1.min(len);
+        }
+
+        fn remove(&mut self, index: usize) -> i32 {
+            self.as_mut_slice()[index..].rotate_left(1);
+            self.pop().unwrap()
+        }
+
+        fn pop(&mut self) -> Option<i32> {
+            self.1 = self.1.checked_sub(1)?;
+            Some(self.0[self.1])
+        }
+
+        fn clear(&mut self) {
+            self.1 = 0;
+        }
+
+        fn retain<F: FnMut(&i32) -> bool>(&mut self, mut f: F) {
+            let mut len = 0;
+            for i in 0..self.1 {
+                if f(&self.0[i]) {
+                    self.0.swap(len, i);
+                    len += 1;
+                }
+            }
+            self.1 = len;
+        }
+    }
+
+    let mut buf = [1, 2, 3, 4, 5];
+    let mut vec1 = create(ShrinkOnly(&mut buf, 5), 1);
+    assert_eq!(vec1, [2, 3, 4, 5]);
+    vec1[0] = 6;
+    assert_eq!(vec1.remove(1), 3);
+    assert_eq!(vec1.pop(), Some(5));
+    vec1.truncate(1);
+    assert_eq!(vec1, [6]);
+    vec1.clear();
+    assert_eq!(vec1.origin_vec().as_slice(), [1]);
+}
+
+#[test]
+fn slice_vec_window() {
+    use core::mem::MaybeUninit;
+
+    let mut buf = [const { MaybeUninit::uninit() }; 5];
+    let mut vec = SliceVec::new(&mut buf);
+    vec.extend(["a", "b"].map(String::from));
+
+    let mut vec1 = vec.offset_mut(1);
+    vec1.extend(["c", "d"].map(String::from));
+    assert_eq!(vec1.drain(1..3).collect::<Vec<_>>(), ["c", "d"]);
+    vec1.resize(4, "e".to_owned());
+    assert_eq!(vec1.try_push("f".to_owned()).unwrap_err().element(), "f");
+    assert_eq!(*vec1.split_off(2), ["e", "e"]);
+    assert_eq!(*vec, ["a", "b", "e"]);
+}
+
+#[test]
+fn slice_like_slices() {
+    fn fill_rest<V: SliceLikeMut<Elem = i32, Slice = [i32]>>(vec: V) -> usize {
+        let mut rest = create(vec, 1);
+        rest.as_mut_slice().fill(9);
+        rest.len()
+    }
+
+    let mut array = [1, 2, 3];
+    assert_eq!(fill_rest(&mut array[..2]), 1);
+    assert_eq!(array, [1, 9, 3]);
+    assert_eq!(fill_rest(&mut array), 2);
+    assert_eq!(array, [1, 9, 9]);
+    assert_eq!(fill_rest([0; 1]), 0);
+}
+
+#[test]
+fn slice_like_read_only() {
+    use std::{rc::Rc, sync::Arc};
+
+    let array = [1, 2, 3];
+    let vec = create(&array[..], 1);
+    assert_eq!(*vec, [2, 3]);
+    assert_eq!(vec.windows(2).count(), 1);
+
+    let vec = create(Rc::<[i32]>::from(array), 2);
+    assert_eq!(vec[0], 3);
+    assert_eq!(vec, Rc::<[i32]>::from([3]));
+
+    let vec = create(Arc::<str>::from("abc"), 1);
+    assert_eq!(*vec, *"bc");
+    assert_eq!(vec.as_slice(), "bc");
+}
+
+#[cfg(feature = "allocator_api")]
+#[test]
+fn allocator_api_window() {
+    use std::alloc::System;
+
+    let mut vec = Vec::new_in(System);
+    vec.extend([0, 1, 2, 3, 4]);
+    let mut vec1 = vec.offset_mut(1);
+    let tail: Vec<_, System> = vec1.split_off(2);
+    assert_eq!(tail, [3, 4]);
+    assert_eq!(vec1.drain(..1).collect::<Vec<_>>(), [1]);
+
+    let mut boxed = Box::new_in(vec, System);
+    boxed.offset_mut(1).push(5);
+    assert_eq!(*boxed, [0, 2, 5]);
+}
+
+#[cfg(feature = "bumpalo")]
+#[test]
+fn bumpalo_window() {
+    use bumpalo::{collections, Bump};
+
+    let bump = Bump::new();
+    let mut vec = collections::Vec::new_in(&bump);
+    vec.extend([0, 1, 2, 3, 4]);
+    let mut vec1 = vec.offset_mut(1);
+    vec1.swap_remove(0);
+    let tail = vec1.split_off(2);
+    assert!(core::ptr::eq(tail.bump(), &bump));
+    assert_eq!(tail, [3]);
+    assert_eq!(vec, [0, 4, 2]);
+
+    let mut s = collections::String::from_str_in("foo", &bump);
+    let mut s1 = s.offset_mut(1);
+    s1.push_str("bar");
+    assert_eq!(s1.split_off(2), "bar");
+    assert_eq!(s1.drain(..1).collect::<String>(), "o");
+    assert_eq!(s, "fo");
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_window() {
+    use bytes::BytesMut;
+
+    let mut buf = BytesMut::with_capacity(16);
+    buf.extend_from_slice(b"len:");
+    let mut body = buf.offset_mut(4);
+    body.extend(*b"abc");
+    body.insert(0, b'x');
+    assert_eq!(body.drain(1..2).collect::<Vec<_>>(), b"a");
+    let ptr = body.as_ptr();
+    let frozen = body.freeze_window();
+    assert_eq!(frozen, b"xbc"[..]);
+    assert_eq!(frozen.as_ptr(), ptr);
+    assert_eq!(buf, b"len:"[..]);
+}
+
+#[cfg(feature = "compact_str")]
+#[test]
+fn compact_str_window() {
+    use compact_str::CompactString;
+
+    let mut s = CompactString::new("key=");
+    let mut value = s.offset_mut(4);
+    value.push_str("从前");
+    value.insert(0, '<');
+    value.push('>');
+    assert_eq!(value.as_str(), "<从前>");
+    assert_eq!(value.drain(1..4).collect::<String>(), "从");
+    assert_eq!(value.split_off(1), "前>");
+    assert_eq!(s, "key=<");
+}
+
+#[cfg(feature = "smartstring")]
+#[test]
+fn smartstring_window() {
+    use smartstring::alias::String as SmartString;
+
+    let mut s = SmartString::from("key=");
+    let mut value = s.offset_mut(4);
+    value.push_str("从前");
+    value.insert(0, '<');
+    value.push('>');
+    assert_eq!(value.as_str(), "<从前>");
+    assert_eq!(value.drain(1..4).collect::<String>(), "从");
+    assert_eq!(value.split_off(1), "前>");
+    assert_eq!(s, "key=<");
+}
+
+#[cfg(feature = "thin-vec")]
+#[test]
+fn thin_vec_window() {
+    use thin_vec::thin_vec;
+
+    let mut vec = thin_vec![0, 1, 2, 3, 4];
+    let mut vec1 = vec.offset_mut(1);
+    assert_eq!(vec1.swap_remove(0), 1);
+    assert_eq!(vec1.pop_if(|x| *x == 3), Some(3));
+    vec1.retain_mut(|x| { *x += 1; true });
+    assert_eq!(vec1.split_off(1), [3]);
+    assert_eq!(vec, [0, 5]);
+}
+
+#[cfg(feature = "ecow")]
+#[test]
+fn ecow_window() {
+    use ecow::{eco_vec, EcoString};
+
+    let vec = eco_vec![0, 1, 2, 3];
+    let shared = vec.clone();
+    let window = vec.offset(1);
+    assert_eq!(window, [1, 2, 3]);
+    assert_eq!(window.origin_vec().as_ptr(), shared.as_ptr());
+
+    let mut vec = window.into_origin_vec();
+    let mut vec1 = vec.offset_mut(1);
+    vec1.push(4);
+    assert_eq!(vec1.drain(..1).collect::<Vec<_>>(), [1]);
+    assert_eq!(vec1.swap_remove(0), 2);
+    assert_eq!(vec, [0, 4, 3]);
+    assert_eq!(shared, [0, 1, 2, 3]);
+
+    let s = EcoString::from("key=");
+    let shared = s.clone();
+    let mut value = s.offset(4);
+    value.push_str("从前有");
+    assert_eq!(value.drain(3..6).collect::<String>(), "前");
+    value.retain(|ch| ch != '有');
+    assert_eq!(value.split_off(0), "从");
+    assert_eq!((value.origin_vec().as_str(), shared.as_str()), ("key=", "key="));
+}
+
+#[cfg(feature = "tinyvec")]
+#[test]
+fn tinyvec_window() {
+    use tinyvec::{array_vec, tiny_vec, ArrayVec, TinyVec};
+
+    let mut vec: ArrayVec<[i32; 4]> = array_vec![0, 1];
+    let mut vec1 = vec.offset_mut(1);
+    vec1.extend([2, 3]);
+    assert_eq!(vec1.try_push(4).unwrap_err().into_element(), 4);
+    assert_eq!(vec1.drain(1..).collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(vec, &[0, 1][..]);
+    assert_eq!(<ArrayVec<[i32; 4]> as Growable>::MAX_CAPACITY, 4);
+
+    let mut vec: TinyVec<[i32; 2]> = tiny_vec![0, 1];
+    let mut vec1 = vec.offset_mut(1);
+    vec1.extend([2, 3]);
+    assert_eq!(vec1.split_off(2), &[3][..]);
+    assert!(vec.is_heap());
+    assert_eq!(vec, &[0, 1, 2][..]);
+}
+
+#[cfg(feature = "imbl")]
+#[test]
+fn imbl_window() {
+    use imbl::{vector, Vector};
+
+    let window = (0..100).collect::<Vector<i32>>().offset(97);
+    let mut fork = window.clone();
+    assert!(fork.origin_vec().ptr_eq(window.origin_vec()));
+
+    fork.insert(0, 9);
+    assert_eq!(fork.remove(3), 99);
+    *fork.get_mut(1).unwrap() += 10;
+    assert_eq!(fork.iter_window().collect::<Vec<_>>(), [&9, &107, &98]);
+    assert_eq!(window.iter_window().collect::<Vec<_>>(), [&97, &98, &99]);
+    assert_eq!(fork.origin_vec().len(), 100);
+
+    let mut vec: Vector<i32> = (0..100).collect();
+    let mut vec1 = vec.offset_mut(90);
+    assert_eq!(vec1.get(9), Some(&99));
+    assert_eq!(vec1.get(10), None);
+    let tail = vec1.split_off(5);
+    assert_eq!(tail, (95..100).collect());
+    vec1.append(&mut vector![-1, -2]);
+    assert_eq!(vec1.drain(..5).collect::<Vec<_>>(), [90, 91, 92, 93, 94]);
+    assert_eq!(vec1.len(), 2);
+    assert_eq!(vec.len(), 92);
+    assert_eq!(vec.back(), Some(&-2));
+}
+
+#[test]
+fn ref_mut_window() {
+    use std::cell::RefCell;
+
+    let cell = RefCell::new(vec![0, 1, 2]);
+    let mut vec1 = cell.borrow_mut().offset(1);
+    vec1.push(3);
+    assert_eq!(vec1.swap_remove(0), 1);
+    assert!(cell.try_borrow().is_err());
+    drop(vec1);
+    assert_eq!(*cell.borrow(), [0, 3, 2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn guard_window() {
+    use std::sync::{Mutex, RwLock};
+
+    let mutex = Mutex::new("foo".to_owned());
+    let mut vec1 = mutex.lock().unwrap().offset(3);
+    vec1.push_str("bar");
+    assert_eq!(vec1.drain(..1).collect::<String>(), "b");
+    assert!(mutex.try_lock().is_err());
+    drop(vec1);
+    assert_eq!(*mutex.lock().unwrap(), "fooar");
+
+    let lock = RwLock::new(vec![0, 1]);
+    let mut guard = lock.write().unwrap();
+    let mut vec1 = guard.offset_mut(1);
+    vec1.insert(0, 5);
+    assert_eq!(vec1, [5, 1]);
+    drop(guard);
+    assert_eq!(*lock.read().unwrap(), [0, 5, 1]);
+}
+
+#[cfg(feature = "file-vec")]
+#[test]
+fn file_vec_window() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("journal");
+
+    let mut journal = FileVec::<u32>::open(&path).unwrap();
+    journal.extend_from_slice(&[7, 7]).unwrap();
+    let mut vec1 = journal.offset_mut(2);
+    vec1.extend(0..10_000);
+    vec1.insert(0, 9);
+    assert_eq!(vec1.remove(1), 0);
+    assert_eq!(vec1.drain(1..9_998).collect::<Vec<_>>(), Vec::from_iter(1..9_998));
+    assert_eq!(vec1.len(), 3);
+    let mut tail = vec1.split_off(1);
+    assert_eq!(tail.pop(), Some(9_999));
+    vec1.append(&mut tail);
+    assert_eq!(vec1.len(), 2);
+    vec1.truncate(1);
+    journal.sync().unwrap();
+    assert!(journal.capacity() > 3);
+    journal.close().unwrap();
+
+    let journal = FileVec::<u32>::open(&path).unwrap();
+    let mut buf = [0; 3];
+    journal.read_at(0, &mut buf).unwrap();
+    assert_eq!(buf, [7, 7, 9]);
+    assert_eq!(journal.len(), 3);
+}
+
+#[cfg(feature = "file-vec")]
+#[test]
+fn file_vec_retain_panic() {
+    let mut journal = FileVec::<u32>::temp().unwrap();
+    journal.extend(0..5000);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        journal.retain_mut(|x| if *x == 3000 { panic!() } else { *x % 2 == 0 });
+    }));
+    assert!(result.is_err());
+    assert_eq!(journal.len(), 1500 + 2000);
+    let mut buf = vec![0; journal.len()];
+    journal.read_at(0, &mut buf).unwrap();
+    assert!(buf.iter().copied().eq((0..3000).step_by(2).chain(3000..5000)));
+}
+
+#[cfg(feature = "memmap2")]
+#[test]
+fn mmap_vec_retain_panic() {
+    let mut journal = MmapVec::<u32>::temp().unwrap();
+    journal.extend(0..10);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        journal.retain_mut(|x| if *x == 5 { panic!() } else { *x % 2 == 0 });
+    }));
+    assert!(result.is_err());
+    assert_eq!(&journal[..], [0, 2, 4, 5, 6, 7, 8, 9]);
+}
+
+#[cfg(feature = "memmap2")]
+#[test]
+fn mmap_vec_window() {
+    let mut journal = MmapVec::<u16>::temp().unwrap();
+    journal.extend_from_slice(&[1, 2]).unwrap();
+    let mut vec1 = journal.offset_mut(1);
+    vec1.extend(3..6);
+    vec1.insert(0, 9);
+    assert_eq!(vec1, [9, 2, 3, 4, 5]);
+    assert_eq!(vec1.drain(1..3).collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(vec1.swap_remove(0), 9);
+    let other = vec1.split_off(1);
+    assert_eq!(&other[..], [4]);
+    assert_eq!(&journal[..], [1, 5]);
+    journal.flush().unwrap();
+    assert!(journal.capacity() > 2);
+    journal.try_shrink_to_fit().unwrap();
+    assert_eq!(journal.capacity(), 2);
+    journal.close().unwrap();
+}
+
+#[test]
+fn rc_no_clone() {
+    use std::{rc::Rc, sync::Arc};
+
+    let mut vec = Rc::new(vec![1, 2, 3]);
+    let shared = vec.clone();
+    assert_eq!(vec.try_offset_mut(1).unwrap_err(), SharedError);
+
+    let mut vec1 = vec.clone().offset(1);
+    assert!(vec1.would_clone());
+    assert_eq!(vec1.try_make_mut().unwrap_err(), SharedError);
+    drop(vec);
+
+    let before = hidden_clones();
+    vec1.push(4);
+    assert!(hidden_clones() > before || cfg!(not(debug_assertions)));
+    assert!(!vec1.would_clone());
+    vec1.try_make_mut().unwrap().push(5);
+    assert_eq!(vec1, [2, 3, 4, 5]);
+    assert_eq!(*shared, [1, 2, 3]);
+
+    let mut vec = Arc::new(vec![1, 2, 3]);
+    let weak = Arc::downgrade(&vec);
+    assert!(!vec.would_clone());
+    assert_eq!(vec.try_offset_mut(2).unwrap_err(), SharedError);
+    drop(weak);
+    vec.try_offset_mut(2).unwrap().push(4);
+    assert_eq!(*vec, [1, 2, 3, 4]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn shared_window_threads() {
+    use std::{sync::Arc, thread};
+
+    let src: Arc<str> = "a bb ccc".into();
+    let mut rest = SharedWindow::new(src.clone());
+    let mut tokens = Vec::new();
+    while let Some(i) = rest.find(' ') {
+        tokens.push(rest.split_to(i));
+        rest = rest.slice(1..);
+    }
+    tokens.push(rest);
+
+    let lens = thread::spawn(move || {
+        tokens.iter().map(|tok| tok.len()).collect::<Vec<_>>()
+    }).join().unwrap();
+    assert_eq!(lens, [1, 2, 3]);
+    assert_eq!(Arc::strong_count(&src), 1);
+}
+
+#[test]
+fn window_as_vec_like() {
+    fn fill<V: VecLike<Elem = i32> + VecLikeSolid>(mut vec: V) -> V::Collection {
+        vec.push(3);
+        vec.insert(0, 1);
+        vec.retain_mut(|x| *x != 2);
+        assert_eq!(vec.swap_remove(0), 1);
+        vec.drain(..1).for_each(drop);
+        vec.split_off(0)
+    }
+
+    let mut vec = vec![9, 2, 5];
+    let mut vec1 = vec.offset_mut(1);
+    assert_eq!(fill(&mut vec1), [5]);
+    assert_eq!(vec1, []);
+    let vec2: OffsetVec<&mut Vec<i32>> = vec1.offset_mut(0);
+    assert_eq!(vec2.origin_offset(), 1);
+    assert_eq!(vec, [9]);
+
+    let mut s1 = String::from("你好").offset(3);
+    Growable::push(&mut s1, '!');
+    Truncatable::retain(&mut s1, |ch: char| ch != '好');
+    assert_eq!(SliceLike::as_slice(&s1), "!");
+    assert_eq!(s1.into_origin_vec(), "你!");
+}
+
+#[test]
+fn window_capacity() {
+    fn shrink<V: Growable>(vec: &mut V, min_capacity: usize) {
+        vec.shrink_to(min_capacity);
+        assert!(vec.capacity() >= min_capacity.max(vec.len()));
+    }
+
+    let mut vec = Vec::with_capacity(64);
+    vec.extend([1, 2, 3, 4]);
+    let mut vec1 = vec.offset_mut(2);
+    shrink(&mut vec1, 8);
+    assert_eq!(vec1.capacity(), 8);
+    shrink(&mut vec1, 0);
+    assert_eq!(vec1.capacity(), 2);
+    assert_eq!(vec.capacity(), 4);
+}
+
+#[test]
+fn delegate_newtype() {
+    struct Line<V> {
+        cells: V,
+    }
+    delegate_vec_like!(impl[V: VecLike + VecLikeSolid + SliceLikeMut] Line<V> => cells: V);
+
+    #[derive(Debug)]
+    struct Text(String);
+    delegate_vec_like!(Text => 0: String; [SeqLike, SliceLike, Truncatable, Growable, Offset, OffsetCheckRef]);
+
+    let mut line = Line { cells: vec![0] };
+    let mut cells = line.offset_mut(1);
+    cells.extend([2, 3]);
+    assert_eq!(cells.drain(..1).collect::<Vec<_>>(), [2]);
+    assert_eq!(cells.swap_remove(0), 3);
+    assert_eq!(line.cells, [0]);
+
+    let mut text = Text("你好".into());
+    let mut tail = text.offset_mut(3);
+    tail.push('!');
+    assert_eq!(tail, "好!");
+    assert_eq!(text.0, "你好!");
+}
+
+#[test]
+#[cfg(feature = "testing")]
+fn conformance_std() {
+    use offset_vec::testing::{check_offset, check_vec_like};
+    use std::{string::String, vec::Vec};
+
+    check_vec_like::<Vec<i32>>(&[]);
+    check_vec_like::<Vec<i32>>(&[1]);
+    check_vec_like::<Vec<i32>>(&[1, 2, 3, 4, 5]);
+    check_vec_like::<String>(&['a', 'b', 'c']);
+    check_offset::<Vec<i32>>(&[1, 2, 3, 4, 5]);
+    check_offset::<String>(&['a', 'b', 'c']);
+}
+
+#[test]
+fn dyn_vec_like_window() {
+    use std::{borrow::Cow, vec::Vec};
+
+    fn edit(buf: &mut dyn DynVecLike<i32>) {
+        let mut rest = buf.offset(1);
+        let mut tail = rest.split_off(1);
+        assert_eq!(rest.drain(..).collect::<Vec<_>>(), [2]);
+        rest.append(&mut tail);
+        rest.resize(3, 0);
+        assert_eq!(rest.remove(0), 3);
+        assert_eq!(rest.len(), 2);
+    }
+
+    let mut vec = vec![1, 2, 3];
+    edit(&mut vec);
+    assert_eq!(vec, [1, 0, 0]);
+
+    let mut cow = Cow::Borrowed(&[1, 2, 3][..]);
+    edit(&mut cow);
+    assert_eq!(*cow, [1, 0, 0]);
+}
+
+#[test]
+fn dyn_vec_like_retain_panic() {
+    let mut vec = vec![1, 2, 3, 4];
+    let buf: &mut dyn DynVecLike<i32> = &mut vec;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        buf.retain(|&x| if x == 3 { panic!() } else { x != 1 });
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec, [2, 3, 4]);
+}
+
+#[test]
+fn dyn_vec_like_retain_window() {
+    let mut vec = vec![1, 2, 3, 4];
+    let buf: &mut dyn DynVecLike<i32> = &mut vec;
+    buf.offset(1).retain(|&x| x % 2 == 0);
+    assert_eq!(vec, [1, 2, 4]);
+
+    let mut s = "a你b好".to_owned();
+    let buf: &mut dyn DynVecLike<char, String> = &mut s;
+    let mut rest = buf.offset(1);
+    rest.retain(|&ch| ch.is_ascii());
+    assert_eq!(rest.len(), 1);
+    assert_eq!(s, "ab");
+}